@@ -1,16 +1,31 @@
 use std::error::Error;
 
-use algo_rust_sdk::IndexerClient;
+use algo_rust_sdk::indexer::queries::{AddressRole, TransactionQuery, TxType};
+use algo_rust_sdk::{Address, IndexerClient};
 
 fn main() -> Result<(), Box<dyn Error>> {
     let indexer_address = "http://localhost:8980";
-    let indexer_token="";
+    let indexer_token = "";
 
     let indexer_client = IndexerClient::new(indexer_address, indexer_token);
 
-     //Print algod status
+    // Print indexer health
+    println!("{:#?}", indexer_client.health()?);
 
-    let _node_status = indexer_client.health()?;
+    // Print the first 1000 payments sent by an account, 100 at a time
+    let address =
+        Address::from_string("47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU")?;
+    let query = TransactionQuery::new()
+        .address(&address)
+        .address_role(AddressRole::Sender)
+        .tx_type(TxType::Payment);
+    let transactions = indexer_client
+        .search_transactions_paginated(&query)
+        .page_size(100)
+        .max_items(1000);
+    for transaction in transactions.iter() {
+        println!("{:#?}", transaction?);
+    }
 
     Ok(())
 }
@@ -13,7 +13,7 @@ fn main() {
 
     let algod_client = AlgodClient::new(algod_address, algod_token);
     let kmd_client = KmdClient::new(kmd_address, kmd_token);
-    let indexer_client = IndexerClient::new(indexer_address,indexer_token);
+    let _indexer_client = IndexerClient::new(indexer_address,indexer_token);
     println!(
         "Algod versions: {:?}",
         algod_client.versions().unwrap().versions
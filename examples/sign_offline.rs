@@ -23,6 +23,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         note: Vec::new(),
         genesis_id: "".to_string(),
         genesis_hash: HashDigest([0; 32]),
+        lease: None,
     };
     let payment = Payment {
         amount,
@@ -1,6 +1,6 @@
 use std::error::Error;
 
-use algo_rust_sdk::transaction::{BaseTransaction, Payment, Transaction, TransactionType};
+use algo_rust_sdk::transaction::builder::PaymentBuilder;
 use algo_rust_sdk::{Address, AlgodClient, KmdClient, MicroAlgos};
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -32,27 +32,12 @@ fn main() -> Result<(), Box<dyn Error>> {
     let gen_response = kmd_client.generate_key(&wallet_handle_token)?;
     let to_address = Address::from_string(&gen_response.address)?;
 
-    let transaction_params = algod_client.transaction_params()?;
+    let params = algod_client.transaction_params()?.into();
 
-    let genesis_id = transaction_params.genesis_id;
-    let genesis_hash = transaction_params.genesis_hash;
-
-    let base = BaseTransaction {
-        sender: from_address,
-        first_valid: transaction_params.last_round,
-        last_valid: transaction_params.last_round + 1000,
-        note: Vec::new(),
-        genesis_id,
-        genesis_hash,
-    };
-
-    let payment = Payment {
-        amount: MicroAlgos(200_000),
-        receiver: to_address,
-        close_remainder_to: None,
-    };
-
-    let transaction = Transaction::new(base, MicroAlgos(1000), TransactionType::Payment(payment))?;
+    let transaction = PaymentBuilder::new(from_address, &params)
+        .receiver(to_address)
+        .amount(MicroAlgos(200_000))
+        .build()?;
 
     let sign_response =
         kmd_client.sign_transaction(&wallet_handle_token, "testpassword", &transaction)?;
@@ -0,0 +1,58 @@
+Feature: Async clients
+  Scenario: Async algod responses
+    Given a mock async algod client responding with "algod/supply.json"
+    When I call the async algod "ledger supply" endpoint
+    Then the mock server should have received "GET /v1/ledger/supply"
+    And the algod response should equal the fixture "algod/supply.json"
+
+  Scenario Outline: Async algod v2 responses
+    Given a mock async algod v2 client responding with "algod_v2/<fixture>"
+    When I call the async algod v2 "<endpoint>" endpoint with "<argument>"
+    Then the mock server should have received "<request>"
+    And the algod v2 response should equal the fixture "algod_v2/<fixture>"
+
+    Examples:
+    | fixture                   | endpoint             | argument                                                   | request                                                                     |
+    | status.json               | status               | none                                                       | GET /v2/status                                                              |
+    | block.json                | block                | 12466                                                      | GET /v2/blocks/12466                                                        |
+    | account.json              | account information  | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | GET /v2/accounts/47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU |
+    | pending_transactions.json | pending transactions | 10                                                         | GET /v2/transactions/pending?max=10                                         |
+    | send.json                 | raw transaction      | gqNzaWfEQA==                                               | POST /v2/transactions                                                       |
+    | params.json               | transaction params   | none                                                       | GET /v2/transactions/params                                                 |
+    | compile.json              | compile teal         | int 1                                                      | POST /v2/teal/compile                                                       |
+
+  Scenario Outline: Async indexer responses
+    Given a mock async indexer client responding with "indexer/<fixture>"
+    When I call the async indexer "<endpoint>" endpoint with "<argument>"
+    Then the mock server should have received "<request>"
+    And the indexer response should equal the fixture "indexer/<fixture>"
+
+    Examples:
+    | fixture           | endpoint            | argument                                                   | request                                                                     |
+    | health.json       | health              | none                                                       | GET /health                                                                 |
+    | account.json      | lookup account      | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | GET /v2/accounts/47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU |
+    | block.json        | lookup block        | 12466                                                      | GET /v2/blocks/12466                                                        |
+    | transactions.json | search transactions | none                                                       | GET /v2/transactions?tx-type=pay                                            |
+
+  Scenario Outline: Async indexer pagination
+    Given a mock async indexer client responding with the pages "<pages>"
+    When I stream over the async indexer transactions sent by "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" with page size "<page size>" and max items "<max items>"
+    Then the mock server should have received the requests "<requests>"
+    And the iterated transaction ids should be "<ids>"
+
+    Examples:
+    | pages                                                                                                | page size | max items | requests                                                                                                                                                                                                                                                                                                                                                                          | ids                                                                                                                                                                                                                                                                          |
+    | indexer/transactions_page_1.json, indexer/transactions_page_2.json, indexer/transactions_page_3.json | 2         | none      | GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=2, GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=2&next=MjEA, GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=2&next=NDMA | 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, RB4FDYF7BAN6B3WFCVDVGRCJYMQHBU6GW62I2ZSWJ4N5OJ2BFNXA, DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4Q, NCTK4W2AB3ZDUQNJSCU6AOWYBDTF2FPWIWU6KXXW6XLZRUPCEQVQ, JOS6IWO3LXRE5LJCOXZRQTI2MSXMOW2LYUAHOLB2SMB4IPAXX7EA |
+    | indexer/transactions_page_1.json, indexer/transactions_page_2.json                                   | 2         | 3         | GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=2, GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=1&next=MjEA                                                                                                                                | 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, RB4FDYF7BAN6B3WFCVDVGRCJYMQHBU6GW62I2ZSWJ4N5OJ2BFNXA, DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4Q                                                                                                             |
+
+  Scenario: Async kmd responses
+    Given a mock async kmd client responding with "kmd/versions.json"
+    When I call the async kmd versions endpoint
+    Then the mock server should have received "GET /versions"
+    And the kmd versions should be "v1"
+
+  Scenario: Async wait for confirmation
+    Given a mock async algod v2 client responding with the fixtures "algod_v2/status.json, algod_v2/pending_transaction_unconfirmed.json, algod_v2/status_after_block.json, algod_v2/pending_transaction.json"
+    When I asynchronously wait for the transaction "5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ" to be confirmed within 10 rounds
+    Then the mock server should have received the requests "GET /v2/status, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, GET /v2/status/wait-for-block-after/12466, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ"
+    And the transaction should have been confirmed in round 12470
@@ -0,0 +1,48 @@
+Feature: Algod v2
+  Scenario Outline: Algod v2 responses
+    Given a mock algod v2 client responding with "algod_v2/<fixture>"
+    When I call the algod v2 "<endpoint>" endpoint with "<argument>"
+    Then the mock server should have received "<request>"
+    And the algod v2 response should equal the fixture "algod_v2/<fixture>"
+
+    Examples:
+    | fixture                   | endpoint                        | argument                                                      | request                                                                                                |
+    | versions.json             | versions                        | none                                                          | GET /versions                                                                                          |
+    | status.json               | status                          | none                                                          | GET /v2/status                                                                                         |
+    | status.json               | status after block              | 12465                                                         | GET /v2/status/wait-for-block-after/12465                                                              |
+    | block.json                | block                           | 12466                                                         | GET /v2/blocks/12466                                                                                   |
+    | supply.json               | ledger supply                   | none                                                          | GET /v2/ledger/supply                                                                                  |
+    | account.json              | account information             | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU    | GET /v2/accounts/47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU                            |
+    | account_asset.json        | account asset information       | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU 31 | GET /v2/accounts/47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU/assets/31                  |
+    | account_application.json  | account application information | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU 45 | GET /v2/accounts/47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU/applications/45            |
+    | asset.json                | asset information               | 31                                                            | GET /v2/assets/31                                                                                      |
+    | application.json          | application information         | 45                                                            | GET /v2/applications/45                                                                                |
+    | pending_transactions.json | pending transactions            | 10                                                            | GET /v2/transactions/pending?max=10                                                                    |
+    | pending_transactions.json | pending transactions by address | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU 0  | GET /v2/accounts/47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU/transactions/pending?max=0 |
+    | pending_transaction.json  | pending transaction information | 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ          | GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ                      |
+    | send.json                 | raw transaction                 | gqNzaWfEQA==                                                  | POST /v2/transactions                                                                                  |
+    | params.json               | transaction params              | none                                                          | GET /v2/transactions/params                                                                            |
+    | compile.json              | compile teal                    | int 1                                                         | POST /v2/teal/compile                                                                                  |
+
+  Scenario Outline: Wait for confirmation
+    Given a mock algod v2 client responding with the fixtures "<fixtures>"
+    When I wait for the transaction "5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ" to be confirmed within <rounds> rounds
+    Then the mock server should have received the requests "<requests>"
+    And the transaction should have been confirmed in round 12470
+
+    Examples:
+    | rounds               | fixtures                                                                                                                                 | requests                                                                                                                                                                                                                        |
+    | 10                   | algod_v2/status.json, algod_v2/pending_transaction.json                                                                                  | GET /v2/status, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ                                                                                                                               |
+    | 10                   | algod_v2/status.json, algod_v2/pending_transaction_unconfirmed.json, algod_v2/status_after_block.json, algod_v2/pending_transaction.json | GET /v2/status, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, GET /v2/status/wait-for-block-after/12466, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ |
+    | 18446744073709551615 | algod_v2/status.json, algod_v2/pending_transaction.json                                                                                  | GET /v2/status, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ                                                                                                                               |
+
+  Scenario Outline: Wait for confirmation failures
+    Given a mock algod v2 client responding with the fixtures "<fixtures>"
+    When I wait for the transaction "5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ" to be confirmed within <rounds> rounds
+    Then the mock server should have received the requests "<requests>"
+    And the call should have failed with "<error>"
+
+    Examples:
+    | rounds | fixtures                                                                                                                                                                               | requests                                                                                                                                                                                                                                                                   | error                                                                                                                        |
+    | 10     | algod_v2/status.json, algod_v2/pending_transaction_unconfirmed.json, algod_v2/status_after_block.json, algod_v2/pending_transaction_rejected.json                                      | GET /v2/status, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, GET /v2/status/wait-for-block-after/12466, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ                                            | Transaction 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ was rejected: txn dead: round 13467 outside of 12466--13466 |
+    | 2      | algod_v2/status.json, algod_v2/pending_transaction_unconfirmed.json, algod_v2/status_after_block.json, algod_v2/pending_transaction_unconfirmed.json, algod_v2/status_after_block.json | GET /v2/status, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, GET /v2/status/wait-for-block-after/12466, GET /v2/transactions/pending/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, GET /v2/status/wait-for-block-after/12467 | Transaction 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ wasn't confirmed after 2 rounds                             |
@@ -4,3 +4,4 @@ Feature: Auction
     And I sign the bid
     And I encode and decode the bid
     Then the bid should still be the same
+    And the signed bid should verify
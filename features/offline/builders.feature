@@ -0,0 +1,87 @@
+Feature: Transaction builders
+  Scenario: Build a payment from the params suggested by algod
+    Given suggested params from the fixture "algod_v2/params.json"
+    When I build a payment of 1000 microalgos from "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" to "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI"
+    Then the transaction should be valid from round 12466 to round 13466 with a fee of 1000 microalgos
+
+  Scenario Outline: Invalid validity window
+    Given suggested params from the fixture "algod_v2/params.json"
+    When I build a payment of 1000 microalgos from "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" to "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI" valid from round <first> to round <last>
+    Then the call should have failed with "<error>"
+
+    Examples:
+    | first | last | error                                                |
+    | 200   | 100  | Last valid round 100 is before first valid round 200 |
+    | 100   | 1101 | Transactions can be valid for at most 1000 rounds    |
+
+  Scenario Outline: Missing required fields
+    Given suggested params from the fixture "algod_v2/params.json"
+    When I build a <kind> from "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" with only its sender
+    Then the call should have failed with "<error>"
+
+    Examples:
+    | kind             | error                                              |
+    | payment          | Payment receiver must be set                       |
+    | key registration | Vote public key must be set                        |
+    | asset transfer   | Asset id must be set                               |
+    | asset config     | Asset params must be set to create an asset        |
+    | asset freeze     | Freeze account must be set                         |
+    | application call | Both programs must be set to create an application |
+
+  Scenario Outline: Fee per byte of the signed transaction
+    Given suggested params from the fixture "algod_v2/params.json"
+    When I build a payment of 1000 microalgos from "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" to "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI" with a fee of 10 microalgos per byte signed by "<signer>"
+    Then the transaction should be valid from round 12466 to round 13466 with a fee of <fee> microalgos
+
+    Examples:
+    | signer                                                                                                                                                                                        | fee  |
+    | single                                                                                                                                                                                        | 2390 |
+    | single PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI                                                                                                                             | 2780 |
+    | single 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU                                                                                                                             | 2390 |
+    | lsig ASABASI=                                                                                                                                                                                 | 2230 |
+    | multisig 1 1 DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM                                                            | 3740 |
+    | multisig 1 2 DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | 4800 |
+
+  Scenario Outline: Pool the fees of a group into one transaction
+    Given suggested params from the fixture "algod_v2/params.json"
+    When I build a payment of 1000 microalgos from "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" to "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI"
+    And I add the transaction to the group
+    And I build a call to application 42 from "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI"
+    And I add the transaction to the group
+    And I pool the group fees into transaction <payer> at <fee per byte> microalgos per byte with <inner> inner transactions for transaction 1
+    Then the fees of the group should be <fees>
+    When I assign the group id
+
+    Examples:
+    | payer | fee per byte | inner | fees    |
+    | 0     | 0            | 0     | 2000, 0 |
+    | 1     | 0            | 0     | 0, 2000 |
+    | 0     | 0            | 3     | 5000, 0 |
+    | 1     | 10           | 3     | 0, 7400 |
+
+  Scenario: Sign a group with pooled fees
+    Given suggested params from the fixture "algod_v2/params.json"
+    And mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    When I build a payment of 1000 microalgos from "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" to "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI"
+    And I add the transaction to the group
+    And I build a call to application 42 from "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU"
+    And I add the transaction to the group
+    And I pool the group fees into transaction 0 at 0 microalgos per byte with 0 inner transactions for transaction 1
+    Then the fees of the group should be 2000, 0
+    When I assign the group id
+    And I sign the transaction group with the private key
+    Then the group id should equal the golden "I63eyh1bQRRlt6hu3hWSQ1vj8NvZfN0Mcf9yGIP/15U="
+    And the signed transaction group should equal the golden "gqNzaWfEQAm5grNFGEz29lNkDk6zVEkkfy1tZJHrzeBZebhmtB73HMJ7ROsmy9NwIYjLHuXp3ZjHd09xV2z/inuAEuBMBAWjdHhuiqNhbXTNA+ijZmVlzQfQomZ2zTCyo2dlbqx0ZXN0bmV0LXYxLjCiZ2jEIEhjtRiks8hOyBDyLU8QgcsPcfBZp6wg3sYvf3DlCToio2dycMQgI63eyh1bQRRlt6hu3hWSQ1vj8NvZfN0Mcf9yGIP/15WibHbNNJqjcmN2xCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWjcGF5gqNzaWfEQBIUuTcPf8pZ71czslTN3jZ/vJU2kHuxNMpQphXzAbu7HRvyH9mggZ7CaYlgK6tOpiOPuUZGq7TRm1tKW7RpAQSjdHhuiKRhcGlkKqJmds0wsqNnZW6sdGVzdG5ldC12MS4womdoxCBIY7UYpLPITsgQ8i1PEIHLD3HwWaesIN7GL39w5Qk6IqNncnDEICOt3sodW0EUZbeobt4VkkNb4/Db2XzdDHH/chiD/9eVomx2zTSao3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaRhcHBs"
+    And the signed transaction group should decode to the same transactions
+
+  Scenario Outline: Invalid fee pooling
+    Given suggested params from the fixture "algod_v2/params.json"
+    When I build a payment of 1000 microalgos from "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" to "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI"
+    And I add the transaction to the group
+    And I pool the group fees into transaction <payer> at 0 microalgos per byte with <inner> inner transactions for transaction 0
+    Then the call should have failed with "<error>"
+
+    Examples:
+    | payer | inner | error                                                                       |
+    | 1     | 0     | Transaction 1 is not in the group of 1 transactions                         |
+    | 0     | 2     | Transaction 0 is not an application call and can't issue inner transactions |
@@ -0,0 +1,57 @@
+Feature: Errors
+  Scenario Outline: Decoding errors
+    When I decode the <kind> "<input>"
+    Then the call should have failed with the decoding error <error>
+
+    Examples:
+    | kind               | input                                                                                                                                                                 | error                                                                 |
+    | address            | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5                                                                                                              | Length { expected: 36, actual: 35 }                                   |
+    | address            | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YA                                                                                                            | Checksum                                                              |
+    | mnemonic           | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able          | Length { expected: 25, actual: 24 }                                   |
+    | mnemonic           | advice algorand treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | UnknownWord("algorand")                                               |
+    | mnemonic           | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able able     | Checksum                                                              |
+    | signed transaction | gaN0eG4=                                                                                                                                                              | Msgpack("IO error while reading marker: failed to fill whole buffer") |
+    | signed transaction | gaN0eG6Bo2ZlZaF4                                                                                                                                                      | Msgpack("invalid type: string \"x\", expected u64")                   |
+
+  Scenario Outline: Invalid multisig addresses
+    When I create a multisig address with version <version> and threshold <threshold> from "<addresses>"
+    Then the call should have failed with a validation error "<error>"
+
+    Examples:
+    | version | threshold | addresses                                                  | error                |
+    | 2       | 1         | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | Unknown msig version |
+    | 1       | 2         | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | Invalid threshold    |
+
+  Scenario Outline: Invalid bytes in responses
+    Given an in-memory transport responding with status 200 and the fixture "<fixture>"
+    When I call the algod v2 "<endpoint>" endpoint through the in-memory transport
+    Then the call should have failed with "<message>"
+
+    Examples:
+    | endpoint                | fixture                                    | message                                                  |
+    | transaction params      | transport/params_short_hash.json           | invalid length 30, expected 32 bytes at line 4 column 60 |
+    | transaction params      | transport/params_invalid_hash.json         | invalid symbol at 43 at line 4 column 64                 |
+    | application information | transport/application_invalid_program.json | invalid length at 4 at line 4 column 33                  |
+
+  Scenario Outline: HTTP errors
+    Given an in-memory transport responding with status <status> and the fixture "<fixture>"
+    When I call the <client> "<endpoint>" endpoint through the in-memory transport
+    Then the call should have failed with the HTTP status <status> and the message "<message>"
+    And the HTTP error <not found> be not found
+    And the HTTP error <overspend> be an overspend
+    And the HTTP error <dead> be a dead transaction
+
+    Examples:
+    | client   | endpoint            | status | fixture                  | message                                                                                                                                                                                                                                                                                               | not found  | overspend  | dead       |
+    | algod v2 | status              | 404    | transport/not_found.json | account not found                                                                                                                                                                                                                                                                                     | should     | should not | should not |
+    | algod v2 | status              | 404    | transport/not_found.txt  | account not found                                                                                                                                                                                                                                                                                     | should     | should not | should not |
+    | algod v2 | raw transaction     | 400    | transport/overspend.json | TransactionPool.Remember: transaction 6XRHQ5PAVMKHYSM4T5OOVLLCX5RBZPXQGIHPKEDGMLRZ33TYBRLA: overspend (account PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI, data {_struct:{} Status:Offline MicroAlgos:{Raw:1000} RewardsBase:0 RewardedMicroAlgos:{Raw:0}}, tried to spend {2000000}) | should not | should     | should not |
+    | algod v2 | raw transaction     | 400    | transport/txn_dead.json  | TransactionPool.Remember: txn dead: round 1000 outside of 100--200                                                                                                                                                                                                                                    | should not | should not | should     |
+    | indexer  | search transactions | 404    | transport/not_found.json | account not found                                                                                                                                                                                                                                                                                     | should     | should not | should not |
+    | kmd      | versions            | 400    | transport/kmd_error.json | wallet not found                                                                                                                                                                                                                                                                                      | should not | should not | should not |
+
+  Scenario: HTTP error data
+    Given an in-memory transport responding with status 400 and the fixture "transport/logic_eval.json"
+    When I call the algod v2 "raw transaction" endpoint through the in-memory transport
+    Then the call should have failed with the HTTP status 400 and the message "TransactionPool.Remember: transaction 6XRHQ5PAVMKHYSM4T5OOVLLCX5RBZPXQGIHPKEDGMLRZ33TYBRLA: logic eval error: assert failed pc=5"
+    And the HTTP error data should be {"app-index":1,"group-index":0,"pc":5}
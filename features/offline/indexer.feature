@@ -0,0 +1,52 @@
+Feature: Indexer
+  Scenario Outline: Indexer lookups
+    Given a mock indexer client responding with "indexer/<fixture>"
+    When I call the indexer "<endpoint>" endpoint with "<argument>"
+    Then the mock server should have received "<request>"
+    And the indexer response should equal the fixture "indexer/<fixture>"
+
+    Examples:
+    | fixture          | endpoint           | argument                                                   | request                                                                     |
+    | health.json      | health             | none                                                       | GET /health                                                                 |
+    | account.json     | lookup account     | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | GET /v2/accounts/47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU |
+    | transaction.json | lookup transaction | 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ       | GET /v2/transactions/5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ   |
+    | asset.json       | lookup asset       | 31                                                         | GET /v2/assets/31                                                           |
+    | application.json | lookup application | 45                                                         | GET /v2/applications/45                                                     |
+    | block.json       | lookup block       | 12466                                                      | GET /v2/blocks/12466                                                        |
+
+  Scenario Outline: Indexer searches
+    Given a mock indexer client responding with "indexer/<fixture>"
+    When I search the indexer for "<endpoint>" with filters "<filters>"
+    Then the mock server should have received "<request>"
+    And the indexer response should equal the fixture "indexer/<fixture>"
+
+    Examples:
+    | fixture             | endpoint             | filters                                                                                                    | request                                                                                                                         |
+    | accounts.json       | accounts             | none                                                                                                       | GET /v2/accounts                                                                                                                |
+    | accounts.json       | accounts             | asset-id=31;currency-greater-than=100;limit=1                                                              | GET /v2/accounts?asset-id=31&currency-greater-than=100&limit=1                                                                  |
+    | accounts.json       | accounts             | auth-addr=PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI;round=12000                           | GET /v2/accounts?auth-addr=PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI&round=12000                               |
+    | transactions.json   | transactions         | none                                                                                                       | GET /v2/transactions                                                                                                            |
+    | transactions.json   | transactions         | address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU;address-role=sender;tx-type=pay         | GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&tx-type=pay         |
+    | transactions.json   | transactions         | address=PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI;address-role=freeze-target;tx-type=afrz | GET /v2/transactions?address=PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI&address-role=freeze-target&tx-type=afrz |
+    | transactions.json   | transactions         | asset-id=31;currency-greater-than=10;currency-less-than=1000;tx-type=axfer                                 | GET /v2/transactions?asset-id=31&currency-greater-than=10&currency-less-than=1000&tx-type=axfer                                 |
+    | transactions.json   | transactions         | after-time=2020-09-13T12:26:40Z;before-time=2020-09-14T00:00:00Z                                           | GET /v2/transactions?after-time=2020-09-13T12%3A26%3A40Z&before-time=2020-09-14T00%3A00%3A00Z                                   |
+    | transactions.json   | transactions         | note-prefix=hello;min-round=12460;max-round=12471;sig-type=msig                                            | GET /v2/transactions?max-round=12471&min-round=12460&note-prefix=aGVsbG8%3D&sig-type=msig                                       |
+    | transactions.json   | transactions         | limit=3;next=VEQAAAAAAAA=                                                                                  | GET /v2/transactions?limit=3&next=VEQAAAAAAAA%3D                                                                                |
+    | transactions.json   | account transactions | address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU;rekey-to=true                           | GET /v2/accounts/47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU/transactions?rekey-to=true                          |
+    | assets.json         | assets               | name=test coin;unit=tst                                                                                    | GET /v2/assets?name=test+coin&unit=tst                                                                                          |
+    | assets.json         | assets               | creator=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU;include-all=true                        | GET /v2/assets?creator=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&include-all=true                              |
+    | asset_balances.json | asset balances       | asset-id=31;currency-less-than=1000000                                                                     | GET /v2/assets/31/balances?currency-less-than=1000000                                                                           |
+    | applications.json   | applications         | application-id=45                                                                                          | GET /v2/applications?application-id=45                                                                                          |
+    | applications.json   | applications         | creator=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU;limit=10                                | GET /v2/applications?creator=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&limit=10                                |
+
+  Scenario Outline: Indexer pagination
+    Given a mock indexer client responding with the pages "<pages>"
+    When I iterate over the indexer transactions sent by "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU" with page size "<page size>" and max items "<max items>"
+    Then the mock server should have received the requests "<requests>"
+    And the iterated transaction ids should be "<ids>"
+
+    Examples:
+    | pages                                                                                                | page size | max items | requests                                                                                                                                                                                                                                                                                                                                                                          | ids                                                                                                                                                                                                                                                                          |
+    | indexer/transactions_page_1.json, indexer/transactions_page_2.json, indexer/transactions_page_3.json | 2         | none      | GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=2, GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=2&next=MjEA, GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=2&next=NDMA | 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, RB4FDYF7BAN6B3WFCVDVGRCJYMQHBU6GW62I2ZSWJ4N5OJ2BFNXA, DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4Q, NCTK4W2AB3ZDUQNJSCU6AOWYBDTF2FPWIWU6KXXW6XLZRUPCEQVQ, JOS6IWO3LXRE5LJCOXZRQTI2MSXMOW2LYUAHOLB2SMB4IPAXX7EA |
+    | indexer/transactions_page_1.json, indexer/transactions_page_2.json                                   | 2         | 3         | GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=2, GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender&limit=1&next=MjEA                                                                                                                                | 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, RB4FDYF7BAN6B3WFCVDVGRCJYMQHBU6GW62I2ZSWJ4N5OJ2BFNXA, DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4Q                                                                                                             |
+    | indexer/transactions_page_1.json                                                                     | none      | 2         | GET /v2/transactions?address=47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU&address-role=sender                                                                                                                                                                                                                                                                       | 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ, RB4FDYF7BAN6B3WFCVDVGRCJYMQHBU6GW62I2ZSWJ4N5OJ2BFNXA                                                                                                                                                                   |
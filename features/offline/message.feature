@@ -0,0 +1,29 @@
+Feature: Signing arbitrary data
+  Scenario: Sign bytes
+    Given mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    When I sign the bytes "aGVsbG8gd29ybGQ=" with the private key
+    Then the signature of the bytes should equal the golden "WA86ti1aa5j7A6pbAipeNRvVGHnsjRaqxHFkCpyqs0agI5IDiA19CU44mH/U/9OVhWT1o/RiJNIVVEp1O7DGBw=="
+    And the signature should verify for the bytes "aGVsbG8gd29ybGQ="
+    And the signature should not verify for the bytes "aGVsbG8gd29ybGQh"
+
+  Scenario: Signed bytes can't authorize a transaction
+    Given payment transaction parameters 4 12466 13466 "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=" "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI" "IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA" 1000 "devnet-v33.0" "6gAVR0Nsv5Y="
+    And mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    When I create the payment transaction
+    And I sign the transaction with the private key
+    And I replace the signature of the transaction with a signature of its bytes to sign
+    Then verifying the signed transaction should fail with "Signature doesn't match the key of 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU"
+
+  Scenario: Sign a message for an application
+    Given mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    When I sign a message for "example.com" with the payload {"nonce": 42, "action": "login"}
+    Then the signed bytes of the message should be {"domain":"example.com","payload":{"action":"login","nonce":42}}
+    And the signed message should verify
+    When I change the domain of the signed message to "example.org"
+    Then verifying the signed message should fail with "Signature doesn't match the key of 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU"
+
+  Scenario: Sign a typed message
+    Given mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    When I sign a login challenge for "example.com" with the nonce 42
+    Then the signed bytes of the login challenge should be {"domain":"example.com","payload":{"action":"login","nonce":42,"session":{"expires":13466,"user":"47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU"}}}
+    And the signed login challenge should verify
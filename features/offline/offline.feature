@@ -0,0 +1,326 @@
+Feature: Offline
+  Scenario: Encode and decode addresses
+    When I generate a key
+    And I decode the address
+    And I encode the address
+    Then the address should still be the same
+
+  Scenario Outline: Mnemonic to and from private key
+    Given mnemonic for private key "<mn>"
+    When I convert the private key back to a mnemonic
+    Then the mnemonic should still be the same as "<mn>"
+
+    Examples:
+    | mn                                                                                                                                                                   |
+    | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor |
+
+  Scenario Outline: Mnemonic to and from master derivation key
+    Given mnemonic for master derivation key "<mn>"
+    When I convert the master derivation key back to a mnemonic
+    Then the mnemonic should still be the same as "<mn>"
+
+    Examples:
+    | mn                                                                                                                                                                   |
+    | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor |
+
+  Scenario Outline: Sign transaction
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the payment transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And the estimated size for a single signer should equal the length of the signed transaction
+    And building the payment transaction from suggested params should give the same transaction
+    And signing it through the account signer should give the same signed transaction
+    And the transaction ID should equal "<txid>"
+
+    Examples:
+    | fee | fv    | lv    | gh                                           | to                                                         | close                                                      | amt  | gen          | note         | mn                                                                                                                                                                   | golden                                                                                                                                                                                                                                                                                                                                                                                                       | txid                                                 |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQPhUAZ3xkDDcc8FvOVo6UinzmKBCqs0woYSfodlmBMfQvGbeUx3Srxy3dyJDzv7rLm26BRv9FnL2/AuT7NYfiAWjdHhui6NhbXTNA+ilY2xvc2XEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXo2ZlZc0EmKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNyY3bEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaNwYXk= | 5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ |
+
+  Scenario Outline: Sign transaction with flat fee
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the flat fee payment transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And building the flat fee payment transaction from suggested params should give the same transaction
+
+    Examples:
+    | fee  | fv    | lv    | gh                                           | to                                                         | close                                                      | amt  | gen          | note         | mn                                                                                                                                                                   | golden                                                                                                                                                                                                                                                                                                                                                                                                       |
+    | 1176 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQPhUAZ3xkDDcc8FvOVo6UinzmKBCqs0woYSfodlmBMfQvGbeUx3Srxy3dyJDzv7rLm26BRv9FnL2/AuT7NYfiAWjdHhui6NhbXTNA+ilY2xvc2XEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXo2ZlZc0EmKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNyY3bEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaNwYXk= |
+
+  Scenario Outline: Multisig address
+    Given multisig addresses "<addresses>"
+    Then the multisig address should equal the golden "<golden>"
+
+    Examples:
+    | addresses                                                                                                                                                                        | golden                                                     |
+    | DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | RWJLJCMQAFZ2ATP2INM2GZTKNL6OULCCUBO5TQPXH3V2KR4AG7U5UA5JNM |
+
+  Scenario Outline: Sign multisig
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    And multisig addresses "<addresses>"
+    When I create the multisig payment transaction
+    And I sign the multisig transaction with the private key
+    Then the multisig transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And verifying the signed transaction should fail with "Multisig has 1 of the 2 signatures it needs"
+    And signing it through the multisig signer should give the same signed transaction
+    And the transaction ID should equal "<txid>"
+
+    Examples:
+    | fee | fv    | lv    | gh                                           | to                                                         | close                                                      | amt  | gen          | note         | mn                                                                                                                                                                   | addresses                                                                                                                                                                        | golden                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                           | txid                                                 |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA | 1000 | devnet-v33.0 | X4Bl4wQ9rCo= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | gqRtc2lng6ZzdWJzaWeTgaJwa8QgG37AsEvqYbeWkJfmy/QH4QinBTUdC8mKvrEiCairgXiBonBrxCAJYzIJU3OJ8HVnEXc5kcfQPhtzyMT1K/av8BqiXPnCcYKicGvEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihoXPEQF6nXZ7CgInd1h7NVspIPFZNhkPL+vGFpTNwH3Eh9gwPM8pf1EPTHfPvjf14sS7xN7mTK+wrz7Odhp4rdWBNUASjdGhyAqF2AaN0eG6Lo2FtdM0D6KVjbG9zZcQgQOk0koglZMvOnFmmm2dUJonpocOiqepbZabopEIf/FejZmVlzQSYomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3Somx2zTSapG5vdGXECF+AZeMEPawqo3JjdsQge2ziT+tbrMCxZOKcIixX9fY9w4fUOQSCWEEcX+EPfAKjc25kxCCNkrSJkAFzoE36Q1mjZmpq/OosQqBd2cH3PuulR4A36aR0eXBlo3BheQ== | TDIO6RJWJIVDDJZELMSX5CPJW7MUNM3QR4YAHYAKHF3W2CFRTI7A |
+
+  Scenario Outline: Append multisig
+    Given encoded multisig transaction "<mtx>"
+    And mnemonic for private key "<mn>"
+    When I append a signature to the multisig transaction
+    Then the multisig transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And the estimated size for the multisig signer should equal the length of the signed transaction
+
+    Examples:
+    | mtx                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                              | mn                                                                                                                                                                | golden                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                   |
+    | gqRtc2lng6ZzdWJzaWeTgqJwa8QgG37AsEvqYbeWkJfmy/QH4QinBTUdC8mKvrEiCairgXihc8RAuLAFE0oma0skOoAmOzEwfPuLYpEWl4LINtsiLrUqWQkDxh4WHb29//YCpj4MFbiSgD2jKYt0XKRD86zKCF4RDYGicGvEIAljMglTc4nwdWcRdzmRx9A+G3PIxPUr9q/wGqJc+cJxgaJwa8Qg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGjdGhyAqF2AaN0eG6Lo2FtdM0D6KVjbG9zZcQgQOk0koglZMvOnFmmm2dUJonpocOiqepbZabopEIf/FejZmVlzQPoomZ2zfMVo2dlbqxkZXZuZXQtdjM4LjCiZ2jEIP6zbDkQFDkAw9pVQsoYNrAP0vgZWRJXzSP2BC+YyDadomx2zfb9pG5vdGXECEUmIgAYUob7o3JjdsQge2ziT+tbrMCxZOKcIixX9fY9w4fUOQSCWEEcX+EPfAKjc25kxCCNkrSJkAFzoE36Q1mjZmpq/OosQqBd2cH3PuulR4A36aR0eXBlo3BheQ== | since during average anxiety protect cherry club long lawsuit loan expand embark forum theory winter park twenty ball kangaroo cram burst board host ability left | gqRtc2lng6ZzdWJzaWeTgqJwa8QgG37AsEvqYbeWkJfmy/QH4QinBTUdC8mKvrEiCairgXihc8RAuLAFE0oma0skOoAmOzEwfPuLYpEWl4LINtsiLrUqWQkDxh4WHb29//YCpj4MFbiSgD2jKYt0XKRD86zKCF4RDYKicGvEIAljMglTc4nwdWcRdzmRx9A+G3PIxPUr9q/wGqJc+cJxoXPEQBAhuyRjsOrnHp3s/xI+iMKiL7QPsh8iJZ22YOJJP0aFUwedMr+a6wfdBXk1OefyrAN1wqJ9rq6O+DrWV1fH0ASBonBrxCDn8PhNBoEd+fMcjYeLEVX0Zx1RoYXCAJCGZ/RJWHBooaN0aHICoXYBo3R4boujYW10zQPopWNsb3NlxCBA6TSSiCVky86cWaabZ1Qmiemhw6Kp6ltlpuikQh/8V6NmZWXNA+iiZnbN8xWjZ2VurGRldm5ldC12MzguMKJnaMQg/rNsORAUOQDD2lVCyhg2sA/S+BlZElfNI/YEL5jINp2ibHbN9v2kbm90ZcQIRSYiABhShvujcmN2xCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqNzbmTEII2StImQAXOgTfpDWaNmamr86ixCoF3Zwfc+66VHgDfppHR5cGWjcGF5 |
+
+  Scenario Outline: Merge multisig
+    Given encoded multisig transactions "<msigtxns>"
+    When I merge the multisig transactions
+    Then the multisig transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And the estimated size for the multisig signer should equal the length of the signed transaction
+
+    Examples:
+    | msigtxns | golden |
+    | gqRtc2lng6ZzdWJzaWeTgqJwa8QgphunEajorK/Yj00fDOcOo1TXKQMvhe6frJxwipP1yiKhc8RA+f+fqZgjzOKV1Y8RlHxk0R5InGx5jsnF1gbKXVq+pAxwqSvtSTjTM7mRY0zH7tbv0dJtcuturoLbmX3lRWZCD4GicGvEIM9tutXmHvqZsk/Hk65YFjn348EccLQrgf9Kp0bzsvnUgaJwa8QgegKRmOgvSz67ItDrNQquyDe17UTgWictMvtqYfpYGCijdGhyAqF2AaN0eG6Io2FtdM0D6KNmZWXNA+iiZnYBomdoxCD+s2w5EBQ5AMPaVULKGDawD9L4GVkSV80j9gQvmMg2naJsds0D6KNyY3bEII4yNZs+IAqmxwEyX1cl45jSec8y0gubN5/lTYQPr95eo3NuZMQgkC7TLOEydGApKJoTita0Z+7jHVqj74oYHwVgXX1YjSKkdHlwZaNwYXk= gqRtc2lng6ZzdWJzaWeTgaJwa8QgphunEajorK/Yj00fDOcOo1TXKQMvhe6frJxwipP1yiKConBrxCDPbbrV5h76mbJPx5OuWBY59+PBHHC0K4H/SqdG87L51KFzxEBfG9erywuPXY/DsgOsadIqou7676GhGH4oSX5K2iSLDCf8L0pFoS3Hmepjsy8FcY62AFIL3Vg5lQLxTdlF670NgaJwa8QgegKRmOgvSz67ItDrNQquyDe17UTgWictMvtqYfpYGCijdGhyAqF2AaN0eG6Io2FtdM0D6KNmZWXNA+iiZnYBomdoxCD+s2w5EBQ5AMPaVULKGDawD9L4GVkSV80j9gQvmMg2naJsds0D6KNyY3bEII4yNZs+IAqmxwEyX1cl45jSec8y0gubN5/lTYQPr95eo3NuZMQgkC7TLOEydGApKJoTita0Z+7jHVqj74oYHwVgXX1YjSKkdHlwZaNwYXk= | gqRtc2lng6ZzdWJzaWeTgqJwa8QgphunEajorK/Yj00fDOcOo1TXKQMvhe6frJxwipP1yiKhc8RA+f+fqZgjzOKV1Y8RlHxk0R5InGx5jsnF1gbKXVq+pAxwqSvtSTjTM7mRY0zH7tbv0dJtcuturoLbmX3lRWZCD4KicGvEIM9tutXmHvqZsk/Hk65YFjn348EccLQrgf9Kp0bzsvnUoXPEQF8b16vLC49dj8OyA6xp0iqi7vrvoaEYfihJfkraJIsMJ/wvSkWhLceZ6mOzLwVxjrYAUgvdWDmVAvFN2UXrvQ2BonBrxCB6ApGY6C9LPrsi0Os1Cq7IN7XtROBaJy0y+2ph+lgYKKN0aHICoXYBo3R4boijYW10zQPoo2ZlZc0D6KJmdgGiZ2jEIP6zbDkQFDkAw9pVQsoYNrAP0vgZWRJXzSP2BC+YyDadomx2zQPoo3JjdsQgjjI1mz4gCqbHATJfVyXjmNJ5zzLSC5s3n+VNhA+v3l6jc25kxCCQLtMs4TJ0YCkomhOK1rRn7uMdWqPvihgfBWBdfViNIqR0eXBlo3BheQ== |
+
+  Scenario Outline: Microalgos to algos
+    When I convert <microalgos> microalgos to algos and back
+    Then it should still be the same amount of microalgos <microalgos>
+
+    Examples:
+    | microalgos   |
+    | 123456789012 |
+    | 123456789013 |
+    | 123456789014 |
+    | 123456789015 |
+    | 123456789016 |
+    | 123456789017 |
+
+  Scenario Outline: Create key registration transaction
+    Given key registration transaction parameters <fee> <fv> <lv> "<gh>" "<votekey>" "<selkey>" <votefst> <votelst> <votekd> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the key registration transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And building the key registration transaction from suggested params should give the same transaction
+
+    Examples:
+    | fee | fv    | lv    | gh                                           | votekey                                      | selkey                                       | votefst | votelst | votekd | gen          | note         | mn                                                                                                                                                                   | golden                                                                                                                                                                                                                                                                                                                                                                                                                                               |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 100     | 102     | 1234   | devnet-v33.0 | X4Bl4wQ9rCo= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQCzedNPqrhDsDBMY9vktJhaXjbJ2MJqr6Opt7Xae1uNQPVczCPin3feuk9YqhmmmLVNbzOgAS6nRh+K8MhgF+AKjdHhujaNmZWXNBQyiZnbNMLKjZ2VurGRldm5ldC12MzMuMKJnaMQgJgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dKibHbNNJqkbm90ZcQIX4Bl4wQ9rCqmc2Vsa2V5xCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWma2V5cmVnp3ZvdGVmc3RkpnZvdGVrZM0E0qd2b3Rla2V5xCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qd2b3RlbHN0Zg== |
+    | 5   | 19    | 345   | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | oImqaSLjuZj63/bNSAjd+eAh5JROOJ6j1cY4eGaJGX4= | uw62NBVKGAtqJ03XdSlcNtO6eq5rXbDMEMVGLbDzMN8= | 123     | 1000    | 65     | none         | none         | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQNi9HHxsOAUwjXOHgpsEJvpe+b6hjLjIoWM9P389HMZ4RWZSB8uVvk1Kg+e52NvxTI3Vm9+Vl9W+dATm3m55ZAOjdHhui6NmZWXNBaWiZnYTomdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds0BWaZzZWxrZXnEILsOtjQVShgLaidN13UpXDbTunqua12wzBDFRi2w8zDfo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaZrZXlyZWendm90ZWZzdHumdm90ZWtkQad2b3Rla2V5xCCgiappIuO5mPrf9s1ICN354CHklE44nqPVxjh4ZokZfqd2b3RlbHN0zQPo                                                 |
+
+  Scenario Outline: Sign asset transfer transaction
+    Given asset transfer transaction parameters <fee> <fv> <lv> "<gh>" <aid> "<rcv>" "<asnd>" "<close>" <aamt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the asset transfer transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And building the asset transfer transaction from suggested params should give the same transaction
+
+    Examples:
+    | fee | fv    | lv    | gh                                           | aid  | rcv                                                        | asnd                                                       | close                                                      | aamt | gen          | note         | mn                                                                                                                                                                   | golden                                                                                                                                                                                                                                                                                                                                                                                                                     |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 1234 | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none                                                       | IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA | 100  | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQPLCW73Ys9vfk0asRA6tiOAQaDdwfycqwdXUrx3Wie5AFLtqXHQC2UVfrdgAM3TpcgBB9y4O0EEgrMbsV12KmQyjdHhujKRhYW10ZKZhY2xvc2XEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXpGFyY3bEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCo2ZlZc0ExKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWlYXhmZXKkeGFpZM0E0g==           |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 1234 | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA | none                                                       | 100  | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQEsfPPoRo4S9+pO2U5ctjpF0vPER0y5Dlhf4xj2CXjmbt+lXv3qi5kvSsXrHqKACxG8gvdsML4x8yCdGWj6GtAmjdHhujKRhYW10ZKRhcmN2xCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqRhc25kxCBA6TSSiCVky86cWaabZ1Qmiemhw6Kp6ltlpuikQh/8V6NmZWXNBLyiZnbNMLKjZ2VurGRldm5ldC12MzMuMKJnaMQgJgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dKibHbNNJqkbm90ZcQI6gAVR0Nsv5ajc25kxCDn8PhNBoEd+fMcjYeLEVX0Zx1RoYXCAJCGZ/RJWHBooaR0eXBlpWF4ZmVypHhhaWTNBNI= |
+
+  Scenario Outline: Sign asset config transaction
+    Given asset config transaction parameters <fee> <fv> <lv> "<gh>" <caid> <total> <decimals> "<df>" "<un>" "<an>" "<au>" "<am>" "<manager>" "<reserve>" "<freeze>" "<clawback>" "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the asset config transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And building the asset config transaction from suggested params should give the same transaction
+
+    Examples:
+    | fee | fv    | lv    | gh                                           | caid | total | decimals | df    | un   | an       | au      | am                                           | manager                                                    | reserve                                                    | freeze                                                     | clawback                                                   | gen          | note         | mn                                                                                                                                                                   | golden |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 0    | 100   | 2        | true  | tst  | testcoin | website | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQNADORSl5EXgpcl5O1yFLTqPoV0L6oPzD2tlb6rvHNic/Nzey0x0+YBr0bwIVjiE4ZQUOjL+GF13z0RstRHfjwWjdHhuiaRhcGFyi6JhbcQgJgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dKiYW6odGVzdGNvaW6iYXWnd2Vic2l0ZaFjxCDn8PhNBoEd+fMcjYeLEVX0Zx1RoYXCAJCGZ/RJWHBooaJkYwKiZGbDoWbEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXoW3EIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihoXLEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCoXRkonVuo3RzdKNmZWXNBtiiZnbNMLKjZ2VurGRldm5ldC12MzMuMKJnaMQgJgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dKibHbNNJqkbm90ZcQI6gAVR0Nsv5ajc25kxCDn8PhNBoEd+fMcjYeLEVX0Zx1RoYXCAJCGZ/RJWHBooaR0eXBlpGFjZmc= |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 1234 | 0     | 0        | false | none | none     | none    | none                                         | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none                                                       | none                                                       | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQLq82FHVLW3dL3NRytHfSP5gBfHN8tzWOJ2zAt/uVgKIKxsanm+ir6YrdPRD+bRkv+s2E64HiVP2W/N1vVvfawqjdHhuiqRhcGFygqFtxCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqFyxCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqRjYWlkzQTSo2ZlZc0EoKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWkYWNmZw== |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 1234 | 0     | 0        | false | none | none     | none    | none                                         | none                                                       | none                                                       | none                                                       | none                                                       | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQNgmu/gogS/CnmJyF9WSYc6ADwf6rxAxhwBtxwRg6TqVn/eA6t9ycxlIZ23Nl63q/q231X+KIL44hGlUfZ+Q3gejdHhuiaRjYWlkzQTSo2ZlZc0D6KJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWkYWNmZw== |
+
+  Scenario Outline: Sign asset freeze transaction
+    Given asset freeze transaction parameters <fee> <fv> <lv> "<gh>" "<fadd>" <faid> "<afrz>" "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the asset freeze transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And building the asset freeze transaction from suggested params should give the same transaction
+
+    Examples:
+    | fee | fv    | lv    | gh                                           | fadd                                                       | faid | afrz  | gen          | note         | mn                                                                                                                                                                   | golden                                                                                                                                                                                                                                                                                                                                                                   |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | 1234 | true  | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQCJ0gIfCIIVRexcFOiWOe90LImFq0BtDBLF8UkfTRzZFIWQ/NIQyWSCeHxMxEN4QT00tVT3A+B915RocOVjJ9QmjdHhui6RhZnJ6w6RmYWRkxCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqRmYWlkzQTSo2ZlZc0EHKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWkYWZyeg== |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | 1234 | false | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQIztezDKRqyFX7aC2x7G0MBpsU48vyLSrJ8hqWhT/IFUuYPa+W0ih/42HQifKkMxTOYaSKzIGcI0tMGnyukfIwKjdHhuiqRmYWRkxCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqRmYWlkzQTSo2ZlZc0EBKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWkYWZyeg==     |
+
+  Scenario Outline: Sign application call transaction
+    Given application call transaction parameters <fee> <fv> <lv> "<gh>" <apid> <apan> "<apap>" "<apsu>" <gnui> <gnbs> <lnui> <lnbs> <apep> "<apaa>" "<apat>" "<apfa>" "<apas>" "<apbx>" "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the application call transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And building the application call transaction from suggested params should give the same transaction
+
+    Examples:
+    | fee | fv    | lv    | gh                                           | apid | apan | apap     | apsu     | gnui | gnbs | lnui | lnbs | apep | apaa      | apat                                                                                                                  | apfa  | apas  | apbx              | gen          | note         | mn                                                                                                                                                                   | golden |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 0    | 1    | AiABASI= | AiABASI= | 1    | 2    | 3    | 4    | 1    | AQI=,AwQ= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI,IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA | 10,11 | 12,13 | 0:Ym94,1:bmFtZQ== | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQFHgOwWKEqIg1Q2PbfZn5v7DxVDRzt2UcBr8Cz20ny9AxcZfglEvbyLvWJKhrEkr3zGh4hXNXgQU9MF+X8arhASjdHhu3gATpGFwYWGSxAIBAsQCAwSkYXBhbgGkYXBhcMQFAiABASKkYXBhc5IMDaRhcGF0ksQge2ziT+tbrMCxZOKcIixX9fY9w4fUOQSCWEEcX+EPfALEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXpGFwYniSgaFuxANib3iCoWkBoW7EBG5hbWWkYXBlcAGkYXBmYZIKC6RhcGdzgqNuYnMCo251aQGkYXBsc4KjbmJzBKNudWkDpGFwc3XEBQIgAQEio2ZlZc0GaKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWkYXBwbA== |
+    | 4   | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | 100  | 0    | none     | none     | 0    | 0    | 0    | 0    | 0    | aGVsbG8=  | none                                                                                                                  | none  | none  | none              | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | gqNzaWfEQGw+mBh6GRvKj0io/1nLBENCw1VIXQMQCjBHcHM9bHxpzXNqdhVX8QNMm1Pi86wAwAgFQ1QkD3euyoF8cCXiSgKjdHhuiqRhcGFhkcQFaGVsbG+kYXBpZGSjZmVlzQPoomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3Somx2zTSapG5vdGXECOoAFUdDbL+Wo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaRhcHBs |
+
+  Scenario Outline: Sign transaction group
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the flat fee payment transaction
+    And I add the transaction to the group
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt2> "<gen>" "<note>"
+    When I create the flat fee payment transaction
+    And I add the transaction to the group
+    And I assign the group id
+    And I sign the transaction group with the private key
+    Then the group id should equal the golden "<grp>"
+    And the signed transaction group should equal the golden "<golden>"
+    And the signed transaction group should decode to the same transactions
+
+    Examples:
+    | fee  | fv    | lv    | gh                                           | to                                                         | close | amt  | amt2 | gen          | note         | mn                                                                                                                                                                   | grp                                          | golden |
+    | 1000 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none  | 1000 | 2000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | 2BdHxsz1EZMRb+DGWfo6X/1qC99t4tccu2JIdFLZ1G4= | gqNzaWfEQK/rYUSZKACCdB2uRKuuWwlTJzm4WL4/tECJ2Ruc1QbC87/T8kA/Sm4wGUhlNp3mPKq9RSICnxyjcPm5iJK5JwCjdHhui6NhbXTNA+ijZmVlzQPoomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3So2dycMQg2BdHxsz1EZMRb+DGWfo6X/1qC99t4tccu2JIdFLZ1G6ibHbNNJqkbm90ZcQI6gAVR0Nsv5ajcmN2xCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWjcGF5gqNzaWfEQH+nULZ5EPAyNdt2F3xLmm0CH6g+a522zQ9oEVjEZxb/BcDcgKAHvqSkawK+f91CdrQrC0tWsg+7GnPz86lLJgujdHhui6NhbXTNB9CjZmVlzQPoomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3So2dycMQg2BdHxsz1EZMRb+DGWfo6X/1qC99t4tccu2JIdFLZ1G6ibHbNNJqkbm90ZcQI6gAVR0Nsv5ajcmN2xCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqNzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWjcGF5 |
+
+  Scenario Outline: Sign rekeyed transaction
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    When I create the flat fee payment transaction
+    And I set the transaction sender to "<snd>"
+    And I rekey the transaction to "<rekey>"
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And the estimated size for a single signer should equal the length of the signed transaction
+    And the transaction ID should equal "<txid>"
+
+    Examples:
+    | fee  | fv    | lv    | gh                                           | to                                                         | close | amt  | gen          | note         | mn                                                                                                                                                                   | snd                                                        | rekey                                                      | golden                                                                                                                                                                                                                                                                                                                                                                                                       | txid                                                 |
+    | 1000 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none  | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA | gqNzaWfEQGDCItinTpJTs4w/K6QQkl0hxd/78iM4O9tdbX9/5zjAx4YRUs9AVuBmwUjVR1IJR/ycv4rPDV2g5+TQv1L+XAWjdHhui6NhbXTNA+ijZmVlzQPoomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3Somx2zTSapG5vdGXECOoAFUdDbL+Wo3JjdsQge2ziT+tbrMCxZOKcIixX9fY9w4fUOQSCWEEcX+EPfAKlcmVrZXnEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaNwYXk= | XRHQS4AI5KUMZTMFFI4GGVDZDLOY6SNAI7WWX2U252EN2QBKKR6Q |
+    | 1000 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none  | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none                                                       | g6RzZ25yxCDn8PhNBoEd+fMcjYeLEVX0Zx1RoYXCAJCGZ/RJWHBooaNzaWfEQLwnB2QF6E0W2b3srePxjp+n7KqClWDdvz38A4YhGMWPnhvd7qdEfB2glc4d+fV5atsNAkEMjjYqpta+lIhXyQ6jdHhuiqNhbXTNA+ijZmVlzQPoomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3Somx2zTSapG5vdGXECOoAFUdDbL+Wo3JjdsQge2ziT+tbrMCxZOKcIixX9fY9w4fUOQSCWEEcX+EPfAKjc25kxCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqR0eXBlo3BheQ== | 4II6YSOIPL4LHTFCUZIMTYDZGTVWRN2YGX6IPAOD52X5L25S5APA |
+
+  Scenario Outline: Sign multisig for rekeyed account
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    And multisig addresses "<addresses>"
+    When I create the flat fee payment transaction
+    And I set the transaction sender to "<snd>"
+    And I sign the multisig transaction with the private key
+    Then the multisig transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And verifying the signed transaction should fail with "Multisig has 1 of the 2 signatures it needs"
+    And the transaction ID should equal "<txid>"
+
+    Examples:
+    | fee  | fv    | lv    | gh                                           | to                                                         | close | amt  | gen          | note         | mn                                                                                                                                                                   | addresses                                                                                                                                                                        | snd                                                        | golden                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                       | txid                                                 |
+    | 1000 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none  | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | g6Rtc2lng6ZzdWJzaWeTgaJwa8QgG37AsEvqYbeWkJfmy/QH4QinBTUdC8mKvrEiCairgXiBonBrxCAJYzIJU3OJ8HVnEXc5kcfQPhtzyMT1K/av8BqiXPnCcYKicGvEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihoXPEQLwnB2QF6E0W2b3srePxjp+n7KqClWDdvz38A4YhGMWPnhvd7qdEfB2glc4d+fV5atsNAkEMjjYqpta+lIhXyQ6jdGhyAqF2AaRzZ25yxCCNkrSJkAFzoE36Q1mjZmpq/OosQqBd2cH3PuulR4A36aN0eG6Ko2FtdM0D6KNmZWXNA+iiZnbNMLKjZ2VurGRldm5ldC12MzMuMKJnaMQgJgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dKibHbNNJqkbm90ZcQI6gAVR0Nsv5ajcmN2xCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqNzbmTEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCpHR5cGWjcGF5 | 4II6YSOIPL4LHTFCUZIMTYDZGTVWRN2YGX6IPAOD52X5L25S5APA |
+
+  Scenario Outline: Sign transaction with lease
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    And lease "<lease>"
+    When I create the flat fee payment transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And building the flat fee payment transaction from suggested params should give the same transaction
+    And the transaction ID should equal "<txid>"
+
+    Examples:
+    | fee  | fv    | lv    | gh                                           | to                                                         | close | amt  | gen          | note         | mn                                                                                                                                                                   | lease                                        | golden                                                                                                                                                                                                                                                                                                                                                                                                   | txid                                                 |
+    | 1000 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none  | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | AQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHyA= | gqNzaWfEQEeEnFGuYSZnnhadM5p7CxHQrw2ZFmCIXJtB9dSPzsPEssB/idHu7w0mgba2EyjGOJ1AVY98yePMdZkFMoIbNASjdHhui6NhbXTNA+ijZmVlzQPoomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3Somx2zTSaomx4xCABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fIKRub3RlxAjqABVHQ2y/lqNyY3bEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaNwYXk= | 7QS4TTE5P7EHVBOUMXE3SSQUZSTTEFOC2CQZNCXPSBDA2EG6Y3HA |
+
+  Scenario: Sign transaction with a zero lease
+    Given payment transaction parameters 1000 12466 13466 "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=" "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI" "none" 1000 "devnet-v33.0" "6gAVR0Nsv5Y="
+    And mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    And lease "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+    When I create the flat fee payment transaction
+    And I sign the transaction with the private key
+    Then the signed transaction should decode to the same transaction without its lease
+    And the signed transaction should verify
+    And building the flat fee payment transaction from suggested params should give the same transaction without its lease
+
+  Scenario Outline: Sign transaction with contract account
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    And logic sig program "<program>" with arguments "<args>"
+    When I create the flat fee payment transaction
+    And I set the transaction sender to "<snd>"
+    And I sign the transaction with the logic sig
+    Then the logic sig address should equal "<snd>"
+    And the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And the estimated size for the logic sig signer should equal the length of the signed transaction
+    And signing it through the logic sig signer should give the same signed transaction
+    And the transaction ID should equal "<txid>"
+
+    Examples:
+    | fee  | fv    | lv    | gh                                           | to                                                         | close | amt  | gen          | note         | mn                                                                                                                                                                   | program  | args      | snd                                                        | golden                                                                                                                                                                                                                                                                                           | txid                                                 |
+    | 1000 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none  | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | ASABASI= | AQID BAUG | 6Z3C3LDVWGMX23BMSYMANACQOSINPFIRF77H7N3AWJZYV6OH6GWTJKVMXY | gqRsc2lngqNhcmeSxAMBAgPEAwQFBqFsxAUBIAEBIqN0eG6Ko2FtdM0D6KNmZWXNA+iiZnbNMLKjZ2VurGRldm5ldC12MzMuMKJnaMQgJgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dKibHbNNJqkbm90ZcQI6gAVR0Nsv5ajcmN2xCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqNzbmTEIPZ2Lax1sZl9bCyWGAaAUHSQ15URL/5/t2Cyc4r5x/GtpHR5cGWjcGF5 | TMEKS47DKXAOJKQAFL4TKQQ5NJE2BXLE5RP2TZQSRNCHF25URQHQ |
+
+  Scenario Outline: Sign transaction with delegated logic sig
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    And logic sig program "<program>" with arguments "<args>"
+    When I sign the logic sig with the private key
+    And I create the flat fee payment transaction
+    And I sign the transaction with the logic sig
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And the signed transaction should verify
+    And the estimated size for the logic sig signer should equal the length of the signed transaction
+    And the transaction ID should equal "<txid>"
+
+    Examples:
+    | fee  | fv    | lv    | gh                                           | to                                                         | close | amt  | gen          | note         | mn                                                                                                                                                                   | program  | args      | golden                                                                                                                                                                                                                                                                                                                                                                                           | txid                                                 |
+    | 1000 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none  | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | ASABASI= | AQID BAUG | gqRsc2lng6NhcmeSxAMBAgPEAwQFBqFsxAUBIAEBIqNzaWfEQE6HXaI5K0lcq50o/y3bWOYsyw9TLi/oorZB4xaNdn1Z14351u2f6JTON478fl+JhIP4HNRRAIh/I8EWXBPpJQ2jdHhuiqNhbXTNA+ijZmVlzQPoomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3Somx2zTSapG5vdGXECOoAFUdDbL+Wo3JjdsQge2ziT+tbrMCxZOKcIixX9fY9w4fUOQSCWEEcX+EPfAKjc25kxCDn8PhNBoEd+fMcjYeLEVX0Zx1RoYXCAJCGZ/RJWHBooaR0eXBlo3BheQ== | EO54V64DZBQ2SYZWL365N5JHUIY2WI5ZADOWLLRPQ3Y5KCVMEMUQ |
+
+  Scenario Outline: Sign transaction with multisig delegated logic sig
+    Given payment transaction parameters <fee> <fv> <lv> "<gh>" "<to>" "<close>" <amt> "<gen>" "<note>"
+    And mnemonic for private key "<mn>"
+    And multisig addresses "<addresses>"
+    And logic sig program "<program>" with arguments "<args>"
+    When I sign the logic sig with the private key and the multisig address
+    And I create the flat fee payment transaction
+    And I set the transaction sender to the multisig address
+    And I sign the transaction with the logic sig
+    Then the signed transaction should equal the golden "<golden>"
+    And the signed transaction should decode to the same transaction
+    And verifying the signed transaction should fail with "Multisig has 1 of the 2 signatures it needs"
+    And the estimated size for the logic sig signer should equal the length of the signed transaction
+    And the transaction ID should equal "<txid>"
+
+    Examples:
+    | fee  | fv    | lv    | gh                                           | to                                                         | close | amt  | gen          | note         | mn                                                                                                                                                                   | program  | args      | addresses                                                                                                                                                                        | golden                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                           | txid                                                 |
+    | 1000 | 12466 | 13466 | JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI= | PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI | none  | 1000 | devnet-v33.0 | 6gAVR0Nsv5Y= | advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor | ASABASI= | AQID BAUG | DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU | gqRsc2lng6NhcmeSxAMBAgPEAwQFBqFsxAUBIAEBIqRtc2lng6ZzdWJzaWeTgaJwa8QgG37AsEvqYbeWkJfmy/QH4QinBTUdC8mKvrEiCairgXiBonBrxCAJYzIJU3OJ8HVnEXc5kcfQPhtzyMT1K/av8BqiXPnCcYKicGvEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihoXPEQE6HXaI5K0lcq50o/y3bWOYsyw9TLi/oorZB4xaNdn1Z14351u2f6JTON478fl+JhIP4HNRRAIh/I8EWXBPpJQ2jdGhyAqF2AaN0eG6Ko2FtdM0D6KNmZWXNA+iiZnbNMLKjZ2VurGRldm5ldC12MzMuMKJnaMQgJgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dKibHbNNJqkbm90ZcQI6gAVR0Nsv5ajcmN2xCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqNzbmTEII2StImQAXOgTfpDWaNmamr86ixCoF3Zwfc+66VHgDfppHR5cGWjcGF5 | HKZLXKYAUYMPFBCZL6XMP4N622LJPR5MO7QMQXODOIFLQZLGURAA |
@@ -0,0 +1,43 @@
+Feature: Transport
+  Scenario Outline: Custom transport
+    Given an in-memory transport responding with status 200 and the fixture "<fixture>"
+    When I call the <client> "<endpoint>" endpoint through the in-memory transport
+    Then the in-memory transport should have received "<request>" with the header "<header>"
+    And the response should equal the fixture "<fixture>"
+
+    Examples:
+    | client   | endpoint            | fixture                   | request                                                | header                     |
+    | algod    | ledger supply       | algod/supply.json         | GET http://algod/v1/ledger/supply                      | X-Algo-API-Token: token    |
+    | algod v2 | status              | algod_v2/status.json      | GET http://algod/v2/status                             | X-Algo-API-Token: token    |
+    | indexer  | search transactions | indexer/transactions.json | GET http://indexer/v2/transactions?limit=2&tx-type=pay | X-Indexer-API-Token: token |
+
+  Scenario Outline: Custom transport with custom headers
+    Given an in-memory transport responding with status 200 and the fixture "<fixture>"
+    And the custom header "X-Custom-Header: value"
+    When I call the <client> "<endpoint>" endpoint through the in-memory transport
+    Then the in-memory transport should have received "<request>" with the header "<header>"
+    And the in-memory transport should have received "<request>" with the header "X-Custom-Header: value"
+
+    Examples:
+    | client   | endpoint            | fixture                   | request                                                | header                     |
+    | algod    | ledger supply       | algod/supply.json         | GET http://algod/v1/ledger/supply                      | X-Algo-API-Token: token    |
+    | algod v2 | status              | algod_v2/status.json      | GET http://algod/v2/status                             | X-Algo-API-Token: token    |
+    | indexer  | search transactions | indexer/transactions.json | GET http://indexer/v2/transactions?limit=2&tx-type=pay | X-Indexer-API-Token: token |
+
+  Scenario: Custom transport with a body
+    Given an in-memory transport responding with status 200 and the fixture "algod_v2/send.json"
+    When I call the algod v2 "raw transaction" endpoint through the in-memory transport
+    Then the in-memory transport should have received "POST http://algod/v2/transactions" with the header "Content-Type: application/x-binary"
+    And the response should equal the fixture "algod_v2/send.json"
+
+  Scenario: Custom transport for kmd
+    Given an in-memory transport responding with status 200 and the fixture "kmd/versions.json"
+    When I call the kmd "versions" endpoint through the in-memory transport
+    Then the in-memory transport should have received "GET http://kmd/versions" with the header "X-KMD-API-Token: token"
+    And the in-memory transport should have received the body "null"
+    And the response should be ["v1"]
+
+  Scenario: Custom transport error status
+    Given an in-memory transport responding with status 404 and the fixture "transport/not_found.json"
+    When I call the algod v2 "status" endpoint through the in-memory transport
+    Then the call should have failed with "HTTP status 404: account not found"
@@ -0,0 +1,26 @@
+Feature: Signature verification
+  Scenario: Transaction changed after signing
+    Given payment transaction parameters 4 12466 13466 "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=" "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI" "IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA" 1000 "devnet-v33.0" "6gAVR0Nsv5Y="
+    And mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    When I create the payment transaction
+    And I sign the transaction with the private key
+    And I change the fee of the signed transaction
+    Then verifying the signed transaction should fail with "Signature doesn't match the key of 47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU"
+
+  Scenario: Transaction without a signature
+    Given payment transaction parameters 4 12466 13466 "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=" "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI" "IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA" 1000 "devnet-v33.0" "6gAVR0Nsv5Y="
+    And mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    When I create the payment transaction
+    And I sign the transaction with the private key
+    And I remove the signature of the signed transaction
+    Then verifying the signed transaction should fail with "Signed transaction must have exactly one kind of signature"
+
+  Scenario: Contract account authorizing another address
+    Given payment transaction parameters 4 12466 13466 "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=" "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI" "IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA" 1000 "devnet-v33.0" "6gAVR0Nsv5Y="
+    And mnemonic for private key "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+    And logic sig program "ASABASI=" with arguments "AQID BAUG"
+    When I create the flat fee payment transaction
+    And I set the transaction sender to "6Z3C3LDVWGMX23BMSYMANACQOSINPFIRF77H7N3AWJZYV6OH6GWTJKVMXY"
+    And I sign the transaction with the logic sig
+    And I set the auth address of the signed transaction to "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI"
+    Then verifying the signed transaction should fail with "Logic sig program doesn't hash to PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI"
@@ -1,18 +1,18 @@
-use data_encoding::BASE32_NOPAD;
 use rand::rngs::OsRng;
 use rand::Rng;
 use ring::signature::Ed25519KeyPair as KeyPairType;
 use ring::signature::KeyPair;
+use serde::Serialize;
 
 use crate::auction::{Bid, SignedBid};
-use crate::crypto::{Address, MultisigAddress, MultisigSignature, MultisigSubsig, Signature};
+use crate::crypto::{
+    prefix_bytes, Address, LogicSig, MultisigAddress, MultisigSignature, MultisigSubsig, Signature,
+};
+use crate::message::{Message, SignedMessage};
 use crate::transaction::{SignedTransaction, Transaction};
 use crate::{Ed25519PublicKey, Error};
-use sha2::Digest;
 use std::borrow::Borrow;
 
-type ChecksumAlg = sha2::Sha512Trunc256;
-
 pub struct Account {
     seed: [u8; 32],
     address: Address,
@@ -60,19 +60,40 @@ impl Account {
     }
 
     fn sign(&self, bytes: &[u8]) -> Signature {
-        let signature = self.key_pair.sign(&bytes);
+        let signature = self.key_pair.sign(bytes);
         // ring returns a signature with padding at the end to make it 105 bytes, only 64 bytes are actually used
         let mut stripped_signature = [0; 64];
         stripped_signature.copy_from_slice(&signature.as_ref()[..64]);
         Signature(stripped_signature)
     }
 
+    /// Sign arbitrary bytes with the account's private key, prefixed with "MX" so that the signature
+    /// can never be valid for a transaction, a bid or a logic signature
+    pub fn sign_bytes(&self, bytes: &[u8]) -> Signature {
+        self.sign(&prefix_bytes(bytes))
+    }
+
+    /// Verifies that the bytes were signed by this account with [Account::sign_bytes]
+    pub fn verify_bytes(&self, bytes: &[u8], signature: &Signature) -> Result<(), Error> {
+        self.address.verify_bytes(bytes, signature)
+    }
+
+    /// Sign a typed message for an off-chain application
+    pub fn sign_message<T: Serialize>(
+        &self,
+        message: Message<T>,
+    ) -> Result<SignedMessage<T>, Error> {
+        let sig = self.sign_bytes(&message.to_bytes()?);
+        Ok(SignedMessage {
+            message,
+            signer: self.address,
+            sig,
+        })
+    }
+
     /// Sign a bid with the account's private key
     pub fn sign_bid(&self, bid: Bid) -> Result<SignedBid, Error> {
-        let encoded_bid = rmp_serde::to_vec_named(&bid)?;
-        let mut prefix_encoded_bid = b"aB".to_vec();
-        prefix_encoded_bid.extend_from_slice(&encoded_bid);
-        let signature = self.sign(&prefix_encoded_bid);
+        let signature = self.sign(&bid.bytes_to_sign()?);
         Ok(SignedBid {
             bid,
             sig: signature,
@@ -80,68 +101,115 @@ impl Account {
     }
 
     /// Sign a transaction with the account's private key
+    ///
+    /// The account may differ from the sender when the sender has been rekeyed to it, in which case
+    /// the auth address of the signed transaction is set to the account's address
     pub fn sign_transaction(&self, transaction: &Transaction) -> Result<SignedTransaction, Error> {
-        let encoded_tx = rmp_serde::to_vec_named(transaction)?;
-        let mut prefix_encoded_tx = b"TX".to_vec();
-        prefix_encoded_tx.extend_from_slice(&encoded_tx);
-        let signature = self.sign(&prefix_encoded_tx);
-        let id = BASE32_NOPAD.encode(&ChecksumAlg::digest(&signature.0));
+        let signature = self.sign(&transaction.bytes_to_sign()?);
         Ok(SignedTransaction {
             transaction: transaction.clone(),
             sig: Some(signature),
             multisig: None,
-            transaction_id: id,
+            logic_sig: None,
+            auth_address: transaction.auth_address(self.address),
+            transaction_id: transaction.id()?,
         })
     }
 
+    /// Sign each transaction of an atomic group with the account's private key
+    pub fn sign_transactions(
+        &self,
+        transactions: &[Transaction],
+    ) -> Result<Vec<SignedTransaction>, Error> {
+        transactions
+            .iter()
+            .map(|transaction| self.sign_transaction(transaction))
+            .collect()
+    }
+
     /// Sign the transaction and populate the multisig field of the signed transaction with the given multisig address
+    ///
+    /// The multisig address may differ from the sender when the sender has been rekeyed to it
     pub fn sign_multisig_transaction(
         &self,
         from: MultisigAddress,
         transaction: &Transaction,
     ) -> Result<SignedTransaction, Error> {
-        if from.address() != transaction.sender {
-            return Err(Error::Api(
-                "Transaction sender does not match multisig identity".to_string(),
-            ));
-        }
-        let my_public_key = Ed25519PublicKey(self.address.0);
-        if !from.public_keys.contains(&my_public_key) {
-            return Err(Error::Api(
-                "Multisig identity does not contain this secret key".to_string(),
-            ));
-        }
         let signed_transaction = self.sign_transaction(transaction)?;
-        let subsigs: Vec<MultisigSubsig> = from
-            .public_keys
-            .iter()
-            .map(|key| {
-                if *key == my_public_key {
-                    MultisigSubsig {
-                        key: *key,
-                        sig: signed_transaction.clone().sig,
-                    }
-                } else {
-                    MultisigSubsig {
-                        key: *key,
-                        sig: None,
-                    }
-                }
-            })
-            .collect();
-        let multisig = MultisigSignature {
-            version: from.version,
-            threshold: from.threshold,
-            subsigs,
-        };
+        let multisig = self.multisig_signature(&from, signed_transaction.sig.unwrap())?;
         Ok(SignedTransaction {
             multisig: Some(multisig),
+            logic_sig: None,
             sig: None,
+            auth_address: transaction.auth_address(from.address()),
             transaction: transaction.clone(),
             transaction_id: signed_transaction.transaction_id,
         })
     }
 
+    /// Sign a logic signature, delegating the authority of the account to the program
+    pub fn sign_logic_sig(&self, logic_sig: &LogicSig) -> Result<LogicSig, Error> {
+        if logic_sig.msig.is_some() {
+            return Err(Error::Validation(
+                "Logic signature is already signed by a multisig address".to_string(),
+            ));
+        }
+        Ok(LogicSig {
+            sig: Some(self.sign(&logic_sig.bytes_to_sign())),
+            ..logic_sig.clone()
+        })
+    }
+
+    /// Sign a logic signature on behalf of the given multisig address, delegating its authority to
+    /// the program once enough of its keys have signed
+    pub fn sign_multisig_logic_sig(
+        &self,
+        from: MultisigAddress,
+        logic_sig: &LogicSig,
+    ) -> Result<LogicSig, Error> {
+        if logic_sig.sig.is_some() {
+            return Err(Error::Validation(
+                "Logic signature is already signed by an account".to_string(),
+            ));
+        }
+        let signature = self.sign(&logic_sig.bytes_to_sign());
+        Ok(LogicSig {
+            msig: Some(self.multisig_signature(&from, signature)?),
+            ..logic_sig.clone()
+        })
+    }
+
+    /// Appends the multisig signature from the given multisig address to the logic signature
+    pub fn append_multisig_logic_sig(
+        &self,
+        from: MultisigAddress,
+        logic_sig: &LogicSig,
+    ) -> Result<LogicSig, Error> {
+        let mut merged = self.sign_multisig_logic_sig(from, logic_sig)?;
+        if let Some(msig) = &logic_sig.msig {
+            let merged_msig = merged.msig.as_mut().unwrap();
+            if merged_msig.version != msig.version
+                || merged_msig.threshold != msig.threshold
+                || merged_msig.subsigs.len() != msig.subsigs.len()
+            {
+                return Err(Error::Validation(
+                    "Logic signature was signed by a different multisig address".to_string(),
+                ));
+            }
+            for (merged_subsig, subsig) in merged_msig.subsigs.iter_mut().zip(&msig.subsigs) {
+                if subsig.key != merged_subsig.key {
+                    return Err(Error::Validation(
+                        "Logic signature msig public keys do not match".to_string(),
+                    ));
+                }
+                if merged_subsig.sig.is_none() {
+                    merged_subsig.sig = subsig.sig
+                }
+            }
+        }
+        Ok(merged)
+    }
+
     /// Appends the multisig signature from the given multisig address to the transaction
     pub fn append_multisig_transaction(
         &self,
@@ -157,33 +225,95 @@ impl Account {
         transactions: &[T],
     ) -> Result<SignedTransaction, Error> {
         if transactions.len() < 2 {
-            return Err(Error::Api("Can't merge only one transaction".to_string()));
+            return Err(Error::Validation(
+                "Can't merge only one transaction".to_string(),
+            ));
         }
         let mut merged = transactions[0].borrow().clone();
         for transaction in transactions {
-            let merged_msig = merged.multisig.as_mut().unwrap();
-            let msig = transaction.borrow().multisig.as_ref().unwrap();
+            let transaction = transaction.borrow();
+            let (merged_msig, msig) = match (merged.multisig.as_mut(), &transaction.multisig) {
+                (Some(merged_msig), Some(msig)) => (merged_msig, msig),
+                _ => {
+                    return Err(Error::Validation(
+                        "Only transactions signed by a multisig address can be merged".to_string(),
+                    ))
+                }
+            };
+            if merged.transaction != transaction.transaction {
+                return Err(Error::Validation(
+                    "Multisig signatures to merge must sign the same transaction".to_string(),
+                ));
+            }
+            if merged.auth_address != transaction.auth_address {
+                return Err(Error::Validation(
+                    "Multisig signatures to merge must have the same auth address".to_string(),
+                ));
+            }
+            if merged_msig.version != msig.version || merged_msig.threshold != msig.threshold {
+                return Err(Error::Validation(
+                    "Multisig signatures to merge must have the same version and threshold"
+                        .to_string(),
+                ));
+            }
             if merged_msig.subsigs.len() != msig.subsigs.len() {
-                return Err(Error::Api(
-                    "Multisig signatures to merge must have the same number of subsignatures".to_string()
+                return Err(Error::Validation(
+                    "Multisig signatures to merge must have the same number of subsignatures"
+                        .to_string(),
                 ));
             }
-            assert_eq!(merged_msig.subsigs.len(), msig.subsigs.len());
             for (merged_subsig, subsig) in merged_msig.subsigs.iter_mut().zip(&msig.subsigs) {
                 if subsig.key != merged_subsig.key {
-                    return Err(Error::Api(
+                    return Err(Error::Validation(
                         "Transaction msig public keys do not match".to_string(),
                     ));
                 }
                 if merged_subsig.sig.is_none() {
                     merged_subsig.sig = subsig.sig
                 } else if merged_subsig.sig != subsig.sig && subsig.sig.is_some() {
-                    return Err(Error::Api(
+                    return Err(Error::Validation(
                         "transaction msig has mismatched signatures".to_string(),
                     ));
                 }
             }
         }
+        merged.transaction_id = merged.transaction.id()?;
         Ok(merged)
     }
+
+    // Builds the multisig signature of the given multisig address with only this account's subsig set
+    fn multisig_signature(
+        &self,
+        from: &MultisigAddress,
+        signature: Signature,
+    ) -> Result<MultisigSignature, Error> {
+        let my_public_key = Ed25519PublicKey(self.address.0);
+        if !from.public_keys.contains(&my_public_key) {
+            return Err(Error::Validation(
+                "Multisig identity does not contain this secret key".to_string(),
+            ));
+        }
+        let subsigs: Vec<MultisigSubsig> = from
+            .public_keys
+            .iter()
+            .map(|key| {
+                if *key == my_public_key {
+                    MultisigSubsig {
+                        key: *key,
+                        sig: Some(signature),
+                    }
+                } else {
+                    MultisigSubsig {
+                        key: *key,
+                        sig: None,
+                    }
+                }
+            })
+            .collect();
+        Ok(MultisigSignature {
+            version: from.version,
+            threshold: from.threshold,
+            subsigs,
+        })
+    }
 }
@@ -5,6 +5,9 @@ use crate::algod::models::{
     TransactionID, TransactionList, TransactionParams, Version,
 };
 use crate::transaction::SignedTransaction;
+#[cfg(feature = "async")]
+use crate::transport::{AsyncHttpTransport, AsyncReqwestTransport};
+use crate::transport::{header_pairs, HttpRequest, HttpTransport, Method, ReqwestTransport};
 use crate::{Error, Round};
 
 const AUTH_HEADER: &str = "X-Algo-API-Token";
@@ -13,7 +16,8 @@ const AUTH_HEADER: &str = "X-Algo-API-Token";
 pub struct AlgodClient {
     url: String,
     token: String,
-    headers: HeaderMap,
+    headers: Vec<(String, String)>,
+    transport: Box<dyn HttpTransport>,
 }
 
 impl AlgodClient {
@@ -22,110 +26,81 @@ impl AlgodClient {
     }
 
     pub fn new_with_headers(address: &str, token: &str, headers: HeaderMap) -> AlgodClient {
+        AlgodClient::new_with_transport(address, token, headers, ReqwestTransport::new())
+    }
+
+    /// Creates a client sending its requests, with the token and custom headers, through the given
+    /// transport
+    pub fn new_with_transport<T>(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+        transport: T,
+    ) -> AlgodClient
+    where
+        T: HttpTransport + 'static,
+    {
         AlgodClient {
             url: address.to_string(),
             token: token.to_string(),
-            headers,
+            headers: header_pairs(&headers),
+            transport: Box::new(transport),
         }
     }
 
     /// Returns Ok if healthy
     pub fn health(&self) -> Result<(), Error> {
-        let _ = reqwest::Client::new()
-            .get(&format!("{}/health", self.url))
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?;
+        let request = self.request(Method::Get, format!("{}/health", self.url));
+        self.transport.send(request)?.error_for_status()?;
         Ok(())
     }
 
     /// Retrieves the current version
     pub fn versions(&self) -> Result<Version, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/versions", self.url))
-            .headers(self.headers.clone())
-            .header(AUTH_HEADER, &self.token)
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/versions", self.url));
+        self.transport.send(request)?.json()
     }
 
     /// Gets the current node status
     pub fn status(&self) -> Result<NodeStatus, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/status", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/v2/status", self.url));
+        self.transport.send(request)?.json()
     }
 
     /// Waits for a block to appear after the specified round and returns the node status at the time
     pub fn status_after_block(&self, round: Round) -> Result<NodeStatus, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!(
-                "{}/v1/status/wait-for-block-after/{}",
-                self.url, round.0
-            ))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(
+            Method::Get,
+            format!("{}/v1/status/wait-for-block-after/{}", self.url, round.0),
+        );
+        self.transport.send(request)?.json()
     }
 
     /// Get the block for the given round
     pub fn block(&self, round: Round) -> Result<Block, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v1/block/{}", self.url, round.0))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/v1/block/{}", self.url, round.0));
+        self.transport.send(request)?.json()
     }
 
     /// Gets the current supply reported by the ledger
     pub fn ledger_supply(&self) -> Result<Supply, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v1/ledger/supply", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/v1/ledger/supply", self.url));
+        self.transport.send(request)?.json()
     }
 
     pub fn account_information(&self, address: &str) -> Result<Account, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v1/account/{}", self.url, address))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/v1/account/{}", self.url, address));
+        self.transport.send(request)?.json()
     }
 
     /// Gets a list of unconfirmed transactions currently in the transaction pool
     ///
     /// Sorted by priority in decreasing order and truncated at the specified limit, or returns all if specified limit is 0
     pub fn pending_transactions(&self, limit: u64) -> Result<PendingTransactions, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v1/transactions/pending", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .query(&[("max", limit.to_string())])
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self
+            .request(Method::Get, format!("{}/v1/transactions/pending", self.url))
+            .query(&[("max", limit.to_string())])?;
+        self.transport.send(request)?.json()
     }
 
     /// Get a specified pending transaction
@@ -133,17 +108,11 @@ impl AlgodClient {
         &self,
         transaction_id: &str,
     ) -> Result<Transaction, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!(
-                "{}/v1/transactions/pending/{}",
-                self.url, transaction_id
-            ))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(
+            Method::Get,
+            format!("{}/v1/transactions/pending/{}", self.url, transaction_id),
+        );
+        self.transport.send(request)?.json()
     }
 
     /// Get a list of confirmed transactions, limited to filters if specified
@@ -172,15 +141,13 @@ impl AlgodClient {
         if let Some(limit) = limit {
             query.push(("max", limit.to_string()))
         }
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/account/{}/transactions", self.url, address))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .query(&query)
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self
+            .request(
+                Method::Get,
+                format!("{}/v2/account/{}/transactions", self.url, address),
+            )
+            .query(&query)?;
+        self.transport.send(request)?.json()
     }
 
     /// Broadcasts a transaction to the network
@@ -192,29 +159,33 @@ impl AlgodClient {
         self.raw_transaction(&bytes)
     }
 
+    /// Broadcasts the signed transactions of an atomic group to the network
+    pub fn send_transactions(
+        &self,
+        signed_transactions: &[SignedTransaction],
+    ) -> Result<TransactionID, Error> {
+        let mut bytes = Vec::new();
+        for signed_transaction in signed_transactions {
+            bytes.extend_from_slice(&rmp_serde::to_vec_named(signed_transaction)?);
+        }
+        self.raw_transaction(&bytes)
+    }
+
     /// Broadcasts a raw transaction to the network
     pub fn raw_transaction(&self, raw: &[u8]) -> Result<TransactionID, Error> {
-        let response = reqwest::Client::new()
-            .post(&format!("{}/v2/transactions", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .body(raw.to_vec())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self
+            .request(Method::Post, format!("{}/v2/transactions", self.url))
+            .body("application/x-binary", raw.to_vec());
+        self.transport.send(request)?.json()
     }
 
     /// Gets the information of a single transaction
     pub fn transaction(&self, transaction_id: &str) -> Result<Transaction, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/transaction/{}", self.url, transaction_id))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/transaction/{}", self.url, transaction_id),
+        );
+        self.transport.send(request)?.json()
     }
 
     /// Gets a specific confirmed transaction
@@ -223,41 +194,249 @@ impl AlgodClient {
         address: &str,
         transaction_id: &str,
     ) -> Result<Transaction, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!(
+        let request = self.request(
+            Method::Get,
+            format!(
                 "{}/v2/account/{}/transaction/{}",
                 self.url, address, transaction_id
-            ))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+            ),
+        );
+        self.transport.send(request)?.json()
     }
 
     /// Gets suggested fee in units of micro-Algos per byte
     pub fn suggested_fee(&self) -> Result<TransactionFee, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/transactions/fee", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/v2/transactions/fee", self.url));
+        self.transport.send(request)?.json()
     }
 
     /// Gets parameters for constructing a new transaction
     pub fn transaction_params(&self) -> Result<TransactionParams, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/transactions/params", self.url))
+        let request = self.request(Method::Get, format!("{}/v2/transactions/params", self.url));
+        self.transport.send(request)?.json()
+    }
+
+    fn request(&self, method: Method, url: String) -> HttpRequest {
+        HttpRequest::new(method, url)
+            .header(AUTH_HEADER, &self.token)
+            .headers(&self.headers)
+    }
+}
+
+/// Async version of [AlgodClient], available with the `async` feature
+#[cfg(feature = "async")]
+pub struct AsyncAlgodClient {
+    url: String,
+    token: String,
+    headers: Vec<(String, String)>,
+    transport: Box<dyn AsyncHttpTransport>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncAlgodClient {
+    pub fn new(address: &str, token: &str) -> AsyncAlgodClient {
+        AsyncAlgodClient::new_with_headers(address, token, HeaderMap::new())
+    }
+
+    pub fn new_with_headers(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+    ) -> AsyncAlgodClient {
+        AsyncAlgodClient::new_with_transport(address, token, headers, AsyncReqwestTransport::new())
+    }
+
+    /// Creates a client sending its requests, with the token and custom headers, through the given
+    /// transport
+    pub fn new_with_transport<T>(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+        transport: T,
+    ) -> AsyncAlgodClient
+    where
+        T: AsyncHttpTransport + 'static,
+    {
+        AsyncAlgodClient {
+            url: address.to_string(),
+            token: token.to_string(),
+            headers: header_pairs(&headers),
+            transport: Box::new(transport),
+        }
+    }
+
+    /// Returns Ok if healthy
+    pub async fn health(&self) -> Result<(), Error> {
+        let request = self.request(Method::Get, format!("{}/health", self.url));
+        self.transport.send(request).await?.error_for_status()?;
+        Ok(())
+    }
+
+    /// Retrieves the current version
+    pub async fn versions(&self) -> Result<Version, Error> {
+        let request = self.request(Method::Get, format!("{}/versions", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the current node status
+    pub async fn status(&self) -> Result<NodeStatus, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/status", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Waits for a block to appear after the specified round and returns the node status at the time
+    pub async fn status_after_block(&self, round: Round) -> Result<NodeStatus, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v1/status/wait-for-block-after/{}", self.url, round.0),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Get the block for the given round
+    pub async fn block(&self, round: Round) -> Result<Block, Error> {
+        let request = self.request(Method::Get, format!("{}/v1/block/{}", self.url, round.0));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the current supply reported by the ledger
+    pub async fn ledger_supply(&self) -> Result<Supply, Error> {
+        let request = self.request(Method::Get, format!("{}/v1/ledger/supply", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    pub async fn account_information(&self, address: &str) -> Result<Account, Error> {
+        let request = self.request(Method::Get, format!("{}/v1/account/{}", self.url, address));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets a list of unconfirmed transactions currently in the transaction pool
+    ///
+    /// Sorted by priority in decreasing order and truncated at the specified limit, or returns all if specified limit is 0
+    pub async fn pending_transactions(&self, limit: u64) -> Result<PendingTransactions, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v1/transactions/pending", self.url))
+            .query(&[("max", limit.to_string())])?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Get a specified pending transaction
+    pub async fn pending_transaction_information(
+        &self,
+        transaction_id: &str,
+    ) -> Result<Transaction, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v1/transactions/pending/{}", self.url, transaction_id),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Get a list of confirmed transactions, limited to filters if specified
+    pub async fn transactions(
+        &self,
+        address: &str,
+        first_round: Option<Round>,
+        last_round: Option<Round>,
+        from_date: Option<String>,
+        to_date: Option<String>,
+        limit: Option<u64>,
+    ) -> Result<TransactionList, Error> {
+        let mut query = Vec::new();
+        if let Some(first_round) = first_round {
+            query.push(("firstRound", first_round.0.to_string()))
+        }
+        if let Some(last_round) = last_round {
+            query.push(("lastRound", last_round.0.to_string()))
+        }
+        if let Some(from_date) = from_date {
+            query.push(("fromDate", from_date))
+        }
+        if let Some(to_date) = to_date {
+            query.push(("toDate", to_date))
+        }
+        if let Some(limit) = limit {
+            query.push(("max", limit.to_string()))
+        }
+        let request = self
+            .request(
+                Method::Get,
+                format!("{}/v2/account/{}/transactions", self.url, address),
+            )
+            .query(&query)?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Broadcasts a transaction to the network
+    pub async fn send_transaction(
+        &self,
+        signed_transaction: &SignedTransaction,
+    ) -> Result<TransactionID, Error> {
+        let bytes = rmp_serde::to_vec_named(signed_transaction)?;
+        self.raw_transaction(&bytes).await
+    }
+
+    /// Broadcasts the signed transactions of an atomic group to the network
+    pub async fn send_transactions(
+        &self,
+        signed_transactions: &[SignedTransaction],
+    ) -> Result<TransactionID, Error> {
+        let mut bytes = Vec::new();
+        for signed_transaction in signed_transactions {
+            bytes.extend_from_slice(&rmp_serde::to_vec_named(signed_transaction)?);
+        }
+        self.raw_transaction(&bytes).await
+    }
+
+    /// Broadcasts a raw transaction to the network
+    pub async fn raw_transaction(&self, raw: &[u8]) -> Result<TransactionID, Error> {
+        let request = self
+            .request(Method::Post, format!("{}/v2/transactions", self.url))
+            .body("application/x-binary", raw.to_vec());
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the information of a single transaction
+    pub async fn transaction(&self, transaction_id: &str) -> Result<Transaction, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/transaction/{}", self.url, transaction_id),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets a specific confirmed transaction
+    pub async fn transaction_information(
+        &self,
+        address: &str,
+        transaction_id: &str,
+    ) -> Result<Transaction, Error> {
+        let request = self.request(
+            Method::Get,
+            format!(
+                "{}/v2/account/{}/transaction/{}",
+                self.url, address, transaction_id
+            ),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets suggested fee in units of micro-Algos per byte
+    pub async fn suggested_fee(&self) -> Result<TransactionFee, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/transactions/fee", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets parameters for constructing a new transaction
+    pub async fn transaction_params(&self) -> Result<TransactionParams, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/transactions/params", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    fn request(&self, method: Method, url: String) -> HttpRequest {
+        HttpRequest::new(method, url)
             .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+            .headers(&self.headers)
     }
 }
 
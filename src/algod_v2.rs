@@ -1,16 +1,24 @@
 use reqwest::header::HeaderMap;
 
-use crate::algod::models::{Account, Block, NodeStatus, PendingTransactions, Supply, Transaction, TransactionFee, TransactionID, TransactionList, TransactionParams, Version};
+use crate::algod_v2::models::{
+    Account, AccountApplicationInformation, AccountAssetInformation, Application, Asset,
+    BlockResponse, CompiledTeal, NodeStatus, PendingTransaction, PendingTransactions, Supply,
+    TransactionID, TransactionParams, Version,
+};
 use crate::transaction::SignedTransaction;
+#[cfg(feature = "async")]
+use crate::transport::{AsyncHttpTransport, AsyncReqwestTransport};
+use crate::transport::{header_pairs, HttpRequest, HttpTransport, Method, ReqwestTransport};
 use crate::{Error, Round};
 
 const AUTH_HEADER: &str = "X-Algo-API-Token";
 
-/// Client for interacting with the Algorand protocol daemon
+/// Client for interacting with the v2 REST API of the Algorand protocol daemon
 pub struct AlgodClientV2 {
     url: String,
     token: String,
-    headers: HeaderMap,
+    headers: Vec<(String, String)>,
+    transport: Box<dyn HttpTransport>,
 }
 
 impl AlgodClientV2 {
@@ -18,166 +26,184 @@ impl AlgodClientV2 {
         AlgodClientV2::new_with_headers(address, token, HeaderMap::new())
     }
 
-    //noinspection ALL
     pub fn new_with_headers(address: &str, token: &str, headers: HeaderMap) -> AlgodClientV2 {
+        AlgodClientV2::new_with_transport(address, token, headers, ReqwestTransport::new())
+    }
+
+    /// Creates a client sending its requests, with the token and custom headers, through the given
+    /// transport
+    pub fn new_with_transport<T>(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+        transport: T,
+    ) -> AlgodClientV2
+    where
+        T: HttpTransport + 'static,
+    {
         AlgodClientV2 {
             url: address.to_string(),
             token: token.to_string(),
-            headers,
+            headers: header_pairs(&headers),
+            transport: Box::new(transport),
         }
     }
 
     /// Returns Ok if healthy
     pub fn health(&self) -> Result<(), Error> {
-        let _ = reqwest::Client::new()
-            .get(&format!("{}/health", self.url))
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?;
+        let request = self.request(Method::Get, format!("{}/health", self.url));
+        self.transport.send(request)?.error_for_status()?;
         Ok(())
     }
+
     /// Retrieves the current version
     pub fn versions(&self) -> Result<Version, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/versions", self.url))
-            .headers(self.headers.clone())
-            .header(AUTH_HEADER, &self.token)
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/versions", self.url));
+        self.transport.send(request)?.json()
     }
 
     /// Gets the current node status
     pub fn status(&self) -> Result<NodeStatus, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/status", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/v2/status", self.url));
+        self.transport.send(request)?.json()
     }
 
     /// Waits for a block to appear after the specified round and returns the node status at the time
     pub fn status_after_block(&self, round: Round) -> Result<NodeStatus, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!(
-                "{}/v2/status/wait-for-block-after/{}",
-                self.url, round.0
-            ))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/status/wait-for-block-after/{}", self.url, round.0),
+        );
+        self.transport.send(request)?.json()
     }
 
     /// Get the block for the given round
-    pub fn block(&self, round: Round) -> Result<Block, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/block/{}", self.url, round.0))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+    pub fn block(&self, round: Round) -> Result<BlockResponse, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/blocks/{}", self.url, round.0));
+        self.transport.send(request)?.json()
     }
 
     /// Gets the current supply reported by the ledger
     pub fn ledger_supply(&self) -> Result<Supply, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/ledger/supply", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/v2/ledger/supply", self.url));
+        self.transport.send(request)?.json()
     }
 
+    /// Gets the balance, asset holdings and application state of an account
     pub fn account_information(&self, address: &str) -> Result<Account, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/account/{}", self.url, address))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self.request(Method::Get, format!("{}/v2/accounts/{}", self.url, address));
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets an account's holding of an asset, and the asset's params if the account created it
+    pub fn account_asset_information(
+        &self,
+        address: &str,
+        asset_id: u64,
+    ) -> Result<AccountAssetInformation, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/accounts/{}/assets/{}", self.url, address, asset_id),
+        );
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets an account's local state of an application, and the application's params if the
+    /// account created it
+    pub fn account_application_information(
+        &self,
+        address: &str,
+        application_id: u64,
+    ) -> Result<AccountApplicationInformation, Error> {
+        let request = self.request(
+            Method::Get,
+            format!(
+                "{}/v2/accounts/{}/applications/{}",
+                self.url, address, application_id
+            ),
+        );
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets the params of an asset
+    pub fn asset_information(&self, asset_id: u64) -> Result<Asset, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/assets/{}", self.url, asset_id));
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets the params and global state of an application
+    pub fn application_information(&self, application_id: u64) -> Result<Application, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/applications/{}", self.url, application_id),
+        );
+        self.transport.send(request)?.json()
     }
 
     /// Gets a list of unconfirmed transactions currently in the transaction pool
     ///
     /// Sorted by priority in decreasing order and truncated at the specified limit, or returns all if specified limit is 0
     pub fn pending_transactions(&self, limit: u64) -> Result<PendingTransactions, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/transactions/pending", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .query(&[("max", limit.to_string())])
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        let request = self
+            .request(Method::Get, format!("{}/v2/transactions/pending", self.url))
+            .query(&[("max", limit.to_string())])?;
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets the unconfirmed transactions sent by the given address
+    ///
+    /// Truncated at the specified limit, or returns all if specified limit is 0
+    pub fn pending_transactions_by_address(
+        &self,
+        address: &str,
+        limit: u64,
+    ) -> Result<PendingTransactions, Error> {
+        let request = self
+            .request(
+                Method::Get,
+                format!("{}/v2/accounts/{}/transactions/pending", self.url, address),
+            )
+            .query(&[("max", limit.to_string())])?;
+        self.transport.send(request)?.json()
     }
 
     /// Get a specified pending transaction
+    ///
+    /// The transaction is still returned for a while after it is confirmed, with the round it was
+    /// confirmed in.
     pub fn pending_transaction_information(
         &self,
         transaction_id: &str,
-    ) -> Result<Transaction, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!(
-                "{}/v2/transactions/pending/{}",
-                self.url, transaction_id
-            ))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+    ) -> Result<PendingTransaction, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/transactions/pending/{}", self.url, transaction_id),
+        );
+        self.transport.send(request)?.json()
     }
 
-    /// Get a list of confirmed transactions, limited to filters if specified
-    pub fn transactions(
+    /// Waits for the transaction to be confirmed, for at most `max_rounds` rounds
+    ///
+    /// Returns the confirmed transaction with the round it was confirmed in and the ids of the
+    /// asset or application it created. Fails with `Error::TransactionRejected` if the node evicts
+    /// the transaction from its pool and with `Error::ConfirmationTimeout` once the rounds are up.
+    pub fn wait_for_confirmation(
         &self,
-        address: &str,
-        first_round: Option<Round>,
-        last_round: Option<Round>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        limit: Option<u64>,
-    ) -> Result<TransactionList, Error> {
-        let mut query = Vec::new();
-        if let Some(first_round) = first_round {
-            query.push(("first-round", first_round.0.to_string()))
-        }
-        if let Some(last_round) = last_round {
-            query.push(("last-round", last_round.0.to_string()))
-        }
-        if let Some(from_date) = from_date {
-            query.push(("fromDate", from_date))
-        }
-        if let Some(to_date) = to_date {
-            query.push(("toDate", to_date))
-        }
-        if let Some(limit) = limit {
-            query.push(("max", limit.to_string()))
+        transaction_id: &str,
+        max_rounds: u64,
+    ) -> Result<PendingTransaction, Error> {
+        let start_round = self.status()?.last_round.0;
+        for round in start_round..start_round.saturating_add(max_rounds) {
+            let pending = self.pending_transaction_information(transaction_id)?;
+            if let Some(confirmed) = confirmation(transaction_id, pending)? {
+                return Ok(confirmed);
+            }
+            self.status_after_block(Round(round))?;
         }
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/account/{}/transactions", self.url, address))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .query(&query)
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+        Err(Error::ConfirmationTimeout {
+            transaction_id: transaction_id.to_string(),
+            rounds: max_rounds,
+        })
     }
 
     /// Broadcasts a transaction to the network
@@ -189,74 +215,904 @@ impl AlgodClientV2 {
         self.raw_transaction(&bytes)
     }
 
+    /// Broadcasts the signed transactions of an atomic group to the network
+    pub fn send_transactions(
+        &self,
+        signed_transactions: &[SignedTransaction],
+    ) -> Result<TransactionID, Error> {
+        let mut bytes = Vec::new();
+        for signed_transaction in signed_transactions {
+            bytes.extend_from_slice(&rmp_serde::to_vec_named(signed_transaction)?);
+        }
+        self.raw_transaction(&bytes)
+    }
+
     /// Broadcasts a raw transaction to the network
     pub fn raw_transaction(&self, raw: &[u8]) -> Result<TransactionID, Error> {
-        let response = reqwest::Client::new()
-            .post(&format!("{}/v2/transactions", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .body(raw.to_vec())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
-    }
-
-    /// Gets the information of a single transaction
-    pub fn transaction(&self, transaction_id: &str) -> Result<Transaction, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/transaction/{}", self.url, transaction_id))
+        let request = self
+            .request(Method::Post, format!("{}/v2/transactions", self.url))
+            .body("application/x-binary", raw.to_vec());
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets parameters for constructing a new transaction
+    pub fn transaction_params(&self) -> Result<TransactionParams, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/transactions/params", self.url));
+        self.transport.send(request)?.json()
+    }
+
+    /// Compiles TEAL source code, the node must have the developer API enabled
+    pub fn compile_teal(&self, source: &[u8]) -> Result<CompiledTeal, Error> {
+        let request = self
+            .request(Method::Post, format!("{}/v2/teal/compile", self.url))
+            .body("application/x-binary", source.to_vec());
+        self.transport.send(request)?.json()
+    }
+
+    fn request(&self, method: Method, url: String) -> HttpRequest {
+        HttpRequest::new(method, url)
             .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+            .headers(&self.headers)
+    }
+}
+
+/// Async version of [AlgodClientV2], available with the `async` feature
+#[cfg(feature = "async")]
+pub struct AsyncAlgodClientV2 {
+    url: String,
+    token: String,
+    headers: Vec<(String, String)>,
+    transport: Box<dyn AsyncHttpTransport>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncAlgodClientV2 {
+    pub fn new(address: &str, token: &str) -> AsyncAlgodClientV2 {
+        AsyncAlgodClientV2::new_with_headers(
+            address,
+            token,
+            HeaderMap::new(),
+        )
     }
 
-    /// Gets a specific confirmed transaction
-    pub fn transaction_information(
+    pub fn new_with_headers(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+    ) -> AsyncAlgodClientV2 {
+        AsyncAlgodClientV2::new_with_transport(
+            address,
+            token,
+            headers,
+            AsyncReqwestTransport::new(),
+        )
+    }
+
+    /// Creates a client sending its requests, with the token and custom headers, through the given
+    /// transport
+    pub fn new_with_transport<T>(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+        transport: T,
+    ) -> AsyncAlgodClientV2
+    where
+        T: AsyncHttpTransport + 'static,
+    {
+        AsyncAlgodClientV2 {
+            url: address.to_string(),
+            token: token.to_string(),
+            headers: header_pairs(&headers),
+            transport: Box::new(transport),
+        }
+    }
+
+    /// Returns Ok if healthy
+    pub async fn health(&self) -> Result<(), Error> {
+        let request = self.request(Method::Get, format!("{}/health", self.url));
+        self.transport.send(request).await?.error_for_status()?;
+        Ok(())
+    }
+
+    /// Retrieves the current version
+    pub async fn versions(&self) -> Result<Version, Error> {
+        let request = self.request(Method::Get, format!("{}/versions", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the current node status
+    pub async fn status(&self) -> Result<NodeStatus, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/status", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Waits for a block to appear after the specified round and returns the node status at the time
+    pub async fn status_after_block(&self, round: Round) -> Result<NodeStatus, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/status/wait-for-block-after/{}", self.url, round.0),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Get the block for the given round
+    pub async fn block(&self, round: Round) -> Result<BlockResponse, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/blocks/{}", self.url, round.0));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the current supply reported by the ledger
+    pub async fn ledger_supply(&self) -> Result<Supply, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/ledger/supply", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the balance, asset holdings and application state of an account
+    pub async fn account_information(&self, address: &str) -> Result<Account, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/accounts/{}", self.url, address));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets an account's holding of an asset, and the asset's params if the account created it
+    pub async fn account_asset_information(
+        &self,
+        address: &str,
+        asset_id: u64,
+    ) -> Result<AccountAssetInformation, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/accounts/{}/assets/{}", self.url, address, asset_id),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets an account's local state of an application, and the application's params if the
+    /// account created it
+    pub async fn account_application_information(
+        &self,
+        address: &str,
+        application_id: u64,
+    ) -> Result<AccountApplicationInformation, Error> {
+        let request = self.request(
+            Method::Get,
+            format!(
+                "{}/v2/accounts/{}/applications/{}",
+                self.url, address, application_id
+            ),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the params of an asset
+    pub async fn asset_information(&self, asset_id: u64) -> Result<Asset, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/assets/{}", self.url, asset_id));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the params and global state of an application
+    pub async fn application_information(&self, application_id: u64) -> Result<Application, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/applications/{}", self.url, application_id),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets a list of unconfirmed transactions currently in the transaction pool
+    ///
+    /// Sorted by priority in decreasing order and truncated at the specified limit, or returns all if specified limit is 0
+    pub async fn pending_transactions(&self, limit: u64) -> Result<PendingTransactions, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/transactions/pending", self.url))
+            .query(&[("max", limit.to_string())])?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the unconfirmed transactions sent by the given address
+    ///
+    /// Truncated at the specified limit, or returns all if specified limit is 0
+    pub async fn pending_transactions_by_address(
         &self,
         address: &str,
+        limit: u64,
+    ) -> Result<PendingTransactions, Error> {
+        let request = self
+            .request(
+                Method::Get,
+                format!("{}/v2/accounts/{}/transactions/pending", self.url, address),
+            )
+            .query(&[("max", limit.to_string())])?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Get a specified pending transaction
+    ///
+    /// The transaction is still returned for a while after it is confirmed, with the round it was
+    /// confirmed in.
+    pub async fn pending_transaction_information(
+        &self,
         transaction_id: &str,
-    ) -> Result<Transaction, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!(
-                "{}/v2/account/{}/transaction/{}",
-                self.url, address, transaction_id
-            ))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+    ) -> Result<PendingTransaction, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/transactions/pending/{}", self.url, transaction_id),
+        );
+        self.transport.send(request).await?.json()
     }
 
-    /// Gets suggested fee in units of micro-Algos per byte
-    pub fn suggested_fee(&self) -> Result<TransactionFee, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/transactions/fee", self.url))
-            .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+    /// Waits for the transaction to be confirmed, for at most `max_rounds` rounds
+    ///
+    /// Returns the confirmed transaction with the round it was confirmed in and the ids of the
+    /// asset or application it created. Fails with `Error::TransactionRejected` if the node evicts
+    /// the transaction from its pool and with `Error::ConfirmationTimeout` once the rounds are up.
+    pub async fn wait_for_confirmation(
+        &self,
+        transaction_id: &str,
+        max_rounds: u64,
+    ) -> Result<PendingTransaction, Error> {
+        let start_round = self.status().await?.last_round.0;
+        for round in start_round..start_round.saturating_add(max_rounds) {
+            let pending = self.pending_transaction_information(transaction_id).await?;
+            if let Some(confirmed) = confirmation(transaction_id, pending)? {
+                return Ok(confirmed);
+            }
+            self.status_after_block(Round(round)).await?;
+        }
+        Err(Error::ConfirmationTimeout {
+            transaction_id: transaction_id.to_string(),
+            rounds: max_rounds,
+        })
+    }
+
+    /// Broadcasts a transaction to the network
+    pub async fn send_transaction(
+        &self,
+        signed_transaction: &SignedTransaction,
+    ) -> Result<TransactionID, Error> {
+        let bytes = rmp_serde::to_vec_named(signed_transaction)?;
+        self.raw_transaction(&bytes).await
+    }
+
+    /// Broadcasts the signed transactions of an atomic group to the network
+    pub async fn send_transactions(
+        &self,
+        signed_transactions: &[SignedTransaction],
+    ) -> Result<TransactionID, Error> {
+        let mut bytes = Vec::new();
+        for signed_transaction in signed_transactions {
+            bytes.extend_from_slice(&rmp_serde::to_vec_named(signed_transaction)?);
+        }
+        self.raw_transaction(&bytes).await
+    }
+
+    /// Broadcasts a raw transaction to the network
+    pub async fn raw_transaction(&self, raw: &[u8]) -> Result<TransactionID, Error> {
+        let request = self
+            .request(Method::Post, format!("{}/v2/transactions", self.url))
+            .body("application/x-binary", raw.to_vec());
+        self.transport.send(request).await?.json()
     }
 
     /// Gets parameters for constructing a new transaction
-    pub fn transaction_params(&self) -> Result<TransactionParams, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/transactions/params", self.url))
+    pub async fn transaction_params(&self) -> Result<TransactionParams, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/transactions/params", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Compiles TEAL source code, the node must have the developer API enabled
+    pub async fn compile_teal(&self, source: &[u8]) -> Result<CompiledTeal, Error> {
+        let request = self
+            .request(Method::Post, format!("{}/v2/teal/compile", self.url))
+            .body("application/x-binary", source.to_vec());
+        self.transport.send(request).await?.json()
+    }
+
+    fn request(&self, method: Method, url: String) -> HttpRequest {
+        HttpRequest::new(method, url)
             .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+            .headers(&self.headers)
     }
 }
 
+// The transaction once it is confirmed, or the reason it never will be
+fn confirmation(
+    transaction_id: &str,
+    pending: PendingTransaction,
+) -> Result<Option<PendingTransaction>, Error> {
+    if pending.confirmed_round.is_some_and(|round| round.0 > 0) {
+        Ok(Some(pending))
+    } else if !pending.pool_error.is_empty() {
+        Err(Error::TransactionRejected {
+            transaction_id: transaction_id.to_string(),
+            pool_error: pending.pool_error,
+        })
+    } else {
+        Ok(None)
+    }
+}
+
+pub mod models {
+    use serde::{Deserialize, Serialize};
+
+    use crate::util::{deserialize_bytes, deserialize_hash, serialize_bytes, serialize_hash};
+    use crate::HashDigest;
+    use crate::MicroAlgos;
+    use crate::Round;
+
+    /// The information about a node status
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NodeStatus {
+        /// Time since the node finished catching up, or 0 if it is still catching up, in nanoseconds
+        #[serde(rename = "catchup-time")]
+        pub catchup_time: i64,
+
+        /// The last round seen
+        #[serde(rename = "last-round")]
+        pub last_round: Round,
+
+        /// The last consensus version supported
+        #[serde(rename = "last-version")]
+        pub last_version: String,
+
+        /// Next version of consensus protocol to use
+        #[serde(rename = "next-version")]
+        pub next_version: String,
+
+        /// The round at which the next consensus version will apply
+        #[serde(rename = "next-version-round")]
+        pub next_version_round: Round,
+
+        /// Whether the next consensus version is supported by this node
+        #[serde(rename = "next-version-supported")]
+        pub next_version_supported: bool,
+
+        /// Whether the node has stopped making progress because it doesn't support the next
+        /// consensus version
+        #[serde(rename = "stopped-at-unsupported-round")]
+        pub stopped_at_unsupported_round: bool,
+
+        /// Time since last round in nanoseconds
+        #[serde(rename = "time-since-last-round")]
+        pub time_since_last_round: i64,
+
+        /// The last catchpoint seen by the node
+        #[serde(
+            rename = "last-catchpoint",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub last_catchpoint: Option<String>,
+
+        /// The catchpoint the node is catching up to, if it is doing a fast catchup
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub catchpoint: Option<String>,
+    }
+
+    /// TransactionID Description
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TransactionID {
+        /// The string encoding of the transaction hash
+        #[serde(rename = "txId")]
+        pub tx_id: String,
+    }
+
+    /// Account information at a given round
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Account {
+        /// The account public key
+        pub address: String,
+
+        /// The total number of MicroAlgos in the account
+        pub amount: MicroAlgos,
+
+        /// The amount of MicroAlgos in the account, without the pending rewards
+        #[serde(rename = "amount-without-pending-rewards")]
+        pub amount_without_pending_rewards: MicroAlgos,
+
+        /// The local state of the applications the account has opted in to
+        #[serde(
+            rename = "apps-local-state",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub apps_local_state: Vec<ApplicationLocalState>,
+
+        /// The number of extra program pages of the applications created by the account
+        #[serde(rename = "apps-total-extra-pages", default)]
+        pub apps_total_extra_pages: u64,
+
+        /// The total schema of the local state of the applications the account has opted in to
+        #[serde(
+            rename = "apps-total-schema",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub apps_total_schema: Option<StateSchema>,
+
+        /// The assets held by the account
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub assets: Vec<AssetHolding>,
+
+        /// The address authorized to sign for the account, set when the account has been rekeyed
+        #[serde(rename = "auth-addr", default, skip_serializing_if = "Option::is_none")]
+        pub auth_addr: Option<String>,
+
+        /// The applications created by the account
+        #[serde(rename = "created-apps", default, skip_serializing_if = "Vec::is_empty")]
+        pub created_apps: Vec<Application>,
+
+        /// The assets created by the account
+        #[serde(
+            rename = "created-assets",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub created_assets: Vec<Asset>,
+
+        /// The minimum balance the account must keep given its holdings, created assets and
+        /// applications
+        #[serde(rename = "min-balance")]
+        pub min_balance: MicroAlgos,
+
+        /// The amount of MicroAlgos of pending rewards in this account
+        #[serde(rename = "pending-rewards")]
+        pub pending_rewards: MicroAlgos,
+
+        /// The rewards level at which the account last received rewards
+        #[serde(rename = "reward-base", default, skip_serializing_if = "Option::is_none")]
+        pub reward_base: Option<u64>,
+
+        /// The total rewards of MicroAlgos the account has received
+        pub rewards: MicroAlgos,
+
+        /// The round for which this information is relevant
+        pub round: Round,
+
+        /// The kind of signature used by the account: "sig", "msig" or "lsig"
+        #[serde(rename = "sig-type", default, skip_serializing_if = "Option::is_none")]
+        pub sig_type: Option<String>,
+
+        /// Status indicates the delegation status of the account's MicroAlgos
+        /// Offline - indicates that the associated account is delegated.
+        /// Online  - indicates that the associated account used as part of the delegation pool.
+        /// NotParticipating - indicates that the associated account is neither a delegator nor a delegate.
+        pub status: String,
+
+        #[serde(rename = "total-apps-opted-in", default)]
+        pub total_apps_opted_in: u64,
+
+        #[serde(rename = "total-assets-opted-in", default)]
+        pub total_assets_opted_in: u64,
+
+        #[serde(rename = "total-created-apps", default)]
+        pub total_created_apps: u64,
+
+        #[serde(rename = "total-created-assets", default)]
+        pub total_created_assets: u64,
+    }
+
+    /// An account's holding of an asset
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetHolding {
+        /// The amount of the asset held, in the asset's base units
+        pub amount: u64,
+
+        #[serde(rename = "asset-id")]
+        pub asset_id: u64,
+
+        #[serde(rename = "is-frozen")]
+        pub is_frozen: bool,
+    }
+
+    /// An asset and its params
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Asset {
+        /// The asset id
+        pub index: u64,
+
+        pub params: AssetParams,
+    }
+
+    /// The params of an asset
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetParams {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub clawback: Option<String>,
+
+        /// The address that created the asset
+        pub creator: String,
+
+        pub decimals: u64,
+
+        #[serde(
+            rename = "default-frozen",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub default_frozen: Option<bool>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub freeze: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub manager: Option<String>,
+
+        /// Base64 encoded commitment to some unspecified asset metadata
+        #[serde(
+            rename = "metadata-hash",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub metadata_hash: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub reserve: Option<String>,
+
+        pub total: u64,
+
+        #[serde(rename = "unit-name", default, skip_serializing_if = "Option::is_none")]
+        pub unit_name: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub url: Option<String>,
+    }
+
+    /// An application and its params
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Application {
+        /// The application id
+        pub id: u64,
+
+        pub params: ApplicationParams,
+    }
+
+    /// The params and global state of an application
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ApplicationParams {
+        #[serde(
+            rename = "approval-program",
+            deserialize_with = "deserialize_bytes",
+            serialize_with = "serialize_bytes"
+        )]
+        pub approval_program: Vec<u8>,
+
+        #[serde(
+            rename = "clear-state-program",
+            deserialize_with = "deserialize_bytes",
+            serialize_with = "serialize_bytes"
+        )]
+        pub clear_state_program: Vec<u8>,
+
+        /// The address that created the application
+        pub creator: String,
 
+        #[serde(
+            rename = "extra-program-pages",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub extra_program_pages: Option<u64>,
 
+        #[serde(
+            rename = "global-state",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub global_state: Vec<TealKeyValue>,
+
+        #[serde(
+            rename = "global-state-schema",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub global_state_schema: Option<StateSchema>,
+
+        #[serde(
+            rename = "local-state-schema",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub local_state_schema: Option<StateSchema>,
+    }
+
+    /// An account's local state of an application
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ApplicationLocalState {
+        /// The application id
+        pub id: u64,
+
+        pub schema: StateSchema,
+
+        #[serde(rename = "key-value", default, skip_serializing_if = "Vec::is_empty")]
+        pub key_value: Vec<TealKeyValue>,
+    }
+
+    /// The number of values of each type an application may store
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct StateSchema {
+        #[serde(rename = "num-byte-slice")]
+        pub num_byte_slice: u64,
+
+        #[serde(rename = "num-uint")]
+        pub num_uint: u64,
+    }
+
+    /// An entry of application state
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TealKeyValue {
+        /// The base64 encoded key
+        pub key: String,
+
+        pub value: TealValue,
+    }
+
+    /// A value of application state, `value_type` is 1 for bytes and 2 for uint
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TealValue {
+        /// The base64 encoded value when it is bytes
+        pub bytes: String,
+
+        #[serde(rename = "type")]
+        pub value_type: u64,
+
+        pub uint: u64,
+    }
+
+    /// An account's holding of an asset and the params of the asset if the account created it
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AccountAssetInformation {
+        #[serde(
+            rename = "asset-holding",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub asset_holding: Option<AssetHolding>,
+
+        #[serde(
+            rename = "created-asset",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub created_asset: Option<AssetParams>,
+
+        /// The round for which this information is relevant
+        pub round: Round,
+    }
+
+    /// An account's local state of an application and the params of the application if the
+    /// account created it
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AccountApplicationInformation {
+        #[serde(
+            rename = "app-local-state",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub app_local_state: Option<ApplicationLocalState>,
+
+        #[serde(rename = "created-app", default, skip_serializing_if = "Option::is_none")]
+        pub created_app: Option<ApplicationParams>,
+
+        /// The round for which this information is relevant
+        pub round: Round,
+    }
+
+    /// A transaction in the transaction pool, or recently confirmed by the node
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PendingTransaction {
+        /// The id of the asset created by the transaction
+        #[serde(rename = "asset-index", default, skip_serializing_if = "Option::is_none")]
+        pub asset_index: Option<u64>,
+
+        /// The id of the application created by the transaction
+        #[serde(
+            rename = "application-index",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub application_index: Option<u64>,
+
+        /// Rewards applied to the close remainder to account
+        #[serde(
+            rename = "close-rewards",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub close_rewards: Option<MicroAlgos>,
+
+        /// The amount sent to the close remainder to account
+        #[serde(
+            rename = "closing-amount",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub closing_amount: Option<MicroAlgos>,
+
+        /// The round the transaction was confirmed in, if it has been
+        #[serde(
+            rename = "confirmed-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub confirmed_round: Option<Round>,
+
+        /// Indicates the transaction was evicted from this node's transaction
+        /// pool (if non-empty).  A non-empty pool_error does not guarantee that the
+        /// transaction will never be committed; other nodes may not have evicted the
+        /// transaction and may attempt to commit it in the future.
+        #[serde(rename = "pool-error", default)]
+        pub pool_error: String,
+
+        /// Rewards applied to the receiver account
+        #[serde(
+            rename = "receiver-rewards",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub receiver_rewards: Option<MicroAlgos>,
+
+        /// Rewards applied to the sender account
+        #[serde(
+            rename = "sender-rewards",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub sender_rewards: Option<MicroAlgos>,
+
+        /// The signed transaction, in the JSON encoding used by algod
+        pub txn: serde_json::Value,
+    }
+
+    /// A potentially truncated list of transactions currently in the node's transaction pool
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PendingTransactions {
+        /// The signed transactions, in the JSON encoding used by algod
+        #[serde(rename = "top-transactions")]
+        pub top_transactions: Vec<serde_json::Value>,
+
+        /// The total number of transactions in the pool
+        #[serde(rename = "total-transactions")]
+        pub total_transactions: u64,
+    }
+
+    /// TransactionParams contains the parameters that help a client construct a new transaction.
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TransactionParams {
+        // The consensus protocol version as of last_round.
+        #[serde(rename = "consensus-version")]
+        pub consensus_version: String,
+
+        /// Transaction fee in units of micro-Algos per byte.
+        /// Fee may fall to zero but transactions must still have a fee of
+        /// at least min_fee for the current network protocol.
+        pub fee: MicroAlgos,
+
+        /// Genesis hash
+        #[serde(
+            rename = "genesis-hash",
+            deserialize_with = "deserialize_hash",
+            serialize_with = "serialize_hash"
+        )]
+        pub genesis_hash: HashDigest,
+
+        /// Genesis ID
+        #[serde(rename = "genesis-id")]
+        pub genesis_id: String,
+
+        // The last round seen
+        #[serde(rename = "last-round")]
+        pub last_round: Round,
+
+        /// The minimum fee of a transaction
+        #[serde(rename = "min-fee")]
+        pub min_fee: MicroAlgos,
+    }
+
+    /// The block for a round
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct BlockResponse {
+        pub block: Block,
+    }
+
+    /// The header of a block and its transactions
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Block {
+        /// Genesis ID
+        #[serde(rename = "gen")]
+        pub genesis_id: String,
+
+        /// Genesis hash
+        #[serde(
+            rename = "gh",
+            deserialize_with = "deserialize_hash",
+            serialize_with = "serialize_hash"
+        )]
+        pub genesis_hash: HashDigest,
+
+        /// The previous block hash
+        #[serde(rename = "prev", default, skip_serializing_if = "String::is_empty")]
+        pub previous_block_hash: String,
+
+        /// The consensus protocol of the block
+        #[serde(rename = "proto")]
+        pub current_protocol: String,
+
+        /// The round of the block
+        #[serde(rename = "rnd", default)]
+        pub round: Round,
+
+        /// The base64 encoded sortition seed
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        pub seed: String,
+
+        /// The number of transactions committed before and including this block
+        #[serde(rename = "tc", default)]
+        pub transaction_counter: u64,
+
+        /// TimeStamp in seconds since epoch
+        #[serde(rename = "ts", default)]
+        pub timestamp: i64,
+
+        /// The base64 encoded root of the merkle tree of the block's transaction ids
+        #[serde(rename = "txn", default, skip_serializing_if = "String::is_empty")]
+        pub transactions_root: String,
+
+        /// The signed transactions of the block, in the JSON encoding used by algod
+        #[serde(rename = "txns", default, skip_serializing_if = "Vec::is_empty")]
+        pub transactions: Vec<serde_json::Value>,
+    }
+
+    /// Supply represents the current supply of MicroAlgos in the system
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Supply {
+        pub current_round: Round,
+        #[serde(rename = "online-money")]
+        pub online_money: MicroAlgos,
+        #[serde(rename = "total-money")]
+        pub total_money: MicroAlgos,
+    }
+
+    /// A compiled TEAL program
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct CompiledTeal {
+        /// The address of the program as a contract account
+        pub hash: String,
+
+        /// The program bytes
+        #[serde(
+            rename = "result",
+            deserialize_with = "deserialize_bytes",
+            serialize_with = "serialize_bytes"
+        )]
+        pub program: Vec<u8>,
+    }
+
+    /// Version contains the current algod version.
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Version {
+        pub build: BuildVersion,
+        #[serde(
+            rename = "genesis_hash_b64",
+            deserialize_with = "deserialize_hash",
+            serialize_with = "serialize_hash"
+        )]
+        pub genesis_hash: HashDigest,
+        pub genesis_id: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub versions: Vec<String>,
+    }
+
+    /// The version of the algod binary
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct BuildVersion {
+        pub branch: String,
+        pub build_number: u64,
+        pub channel: String,
+        pub commit_hash: String,
+        pub major: u64,
+        pub minor: u64,
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::crypto::{Address, Signature};
+use crate::Error;
 
 /// A bid by a user as part of an auction.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -35,3 +36,21 @@ pub struct SignedBid {
     /// A signature by the bidder, as identified in the bid ([Bid.bidder_key]) over the hash of the Bid.
     pub sig: Signature,
 }
+
+impl Bid {
+    /// Returns the encoding of the bid prefixed with "aB", the bytes that are signed
+    pub fn bytes_to_sign(&self) -> Result<Vec<u8>, Error> {
+        let mut prefix_encoded_bid = b"aB".to_vec();
+        prefix_encoded_bid.extend_from_slice(&rmp_serde::to_vec_named(self)?);
+        Ok(prefix_encoded_bid)
+    }
+}
+
+impl SignedBid {
+    /// Verifies that the bid was signed by the bidder
+    pub fn verify(&self) -> Result<(), Error> {
+        self.bid
+            .bidder_key
+            .verify_signature(&self.bid.bytes_to_sign()?, &self.sig)
+    }
+}
@@ -1,15 +1,17 @@
 use data_encoding::BASE32_NOPAD;
+use ring::signature::{UnparsedPublicKey, ED25519};
 use serde::Deserialize;
 use sha2::Digest;
 
-use crate::Ed25519PublicKey;
+use crate::transaction::{SignedTransaction, Transaction};
+use crate::{DecodingError, Ed25519PublicKey, Error};
 type ChecksumAlg = sha2::Sha512Trunc256;
 
 const CHECKSUM_LEN: usize = 4;
 const HASH_LEN: usize = 32;
 
 /// Public key address
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
 pub struct Address(pub [u8; HASH_LEN]);
 
 impl Address {
@@ -18,22 +20,24 @@ impl Address {
     }
 
     /// Decode address from base64 string with checksum
-    pub fn from_string(string: &str) -> Result<Address, String> {
-        let checksum_address = match BASE32_NOPAD.decode(string.as_bytes()) {
-            Ok(decoded) => decoded,
-            Err(err) => return Err(format!("Error decoding base32: {:?}", err)),
-        };
+    pub fn from_string(string: &str) -> Result<Address, Error> {
+        let checksum_address = BASE32_NOPAD
+            .decode(string.as_bytes())
+            .map_err(|err| DecodingError::Encoding(err.to_string()))?;
         if checksum_address.len() != (HASH_LEN + CHECKSUM_LEN) {
-            return Err("Input string is an invalid address. Wrong length".to_string());
+            return Err(Error::Decoding(DecodingError::Length {
+                expected: HASH_LEN + CHECKSUM_LEN,
+                actual: checksum_address.len(),
+            }));
         }
         let (address, checksum) = checksum_address.split_at(HASH_LEN);
-        let hashed = ChecksumAlg::digest(&address);
+        let hashed = ChecksumAlg::digest(address);
         if &hashed[(HASH_LEN - CHECKSUM_LEN)..] == checksum {
             let mut bytes = [0; HASH_LEN];
             bytes.copy_from_slice(address);
             Ok(Address::new(bytes))
         } else {
-            Err("Input checksum did not validate".to_string())
+            Err(Error::Decoding(DecodingError::Checksum))
         }
     }
 
@@ -44,10 +48,52 @@ impl Address {
         let checksum_address = [&self.0, checksum].concat();
         BASE32_NOPAD.encode(&checksum_address)
     }
+
+    /// Verifies that the bytes were signed by the key of this address with [Account::sign_bytes]
+    ///
+    /// [Account::sign_bytes]: crate::account::Account::sign_bytes
+    pub fn verify_bytes(&self, bytes: &[u8], signature: &Signature) -> Result<(), Error> {
+        self.verify_signature(&prefix_bytes(bytes), signature)
+    }
+
+    // Fails unless the message was signed by the key of this single signature address
+    pub(crate) fn verify_signature(
+        &self,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        if Ed25519PublicKey(self.0).verify(message, signature) {
+            Ok(())
+        } else {
+            Err(Error::Verification(format!(
+                "Signature doesn't match the key of {}",
+                self.encode_string()
+            )))
+        }
+    }
+}
+
+// Prefixes arbitrary bytes with "MX", so that their signatures can't be valid for a transaction
+pub(crate) fn prefix_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut prefix_encoded_bytes = b"MX".to_vec();
+    prefix_encoded_bytes.extend_from_slice(bytes);
+    prefix_encoded_bytes
+}
+
+impl Ed25519PublicKey {
+    /// Whether the message was signed by the private key of this public key
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        UnparsedPublicKey::new(&ED25519, &self.0)
+            .verify(message, &signature.0)
+            .is_ok()
+    }
 }
 
 /// Convenience struct for handling multisig public identities
-#[derive(Debug, Clone)]
+///
+/// It can be shared between co-signers as a `msig:<version>:<threshold>:<addresses>` descriptor
+/// through its `Display` and `FromStr` implementations, or encoded with serde as msgpack or json.
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct MultisigAddress {
     /// the version of this multisig
     pub version: u8,
@@ -62,11 +108,11 @@ impl MultisigAddress {
         version: u8,
         threshold: u8,
         addresses: &[Address],
-    ) -> Result<MultisigAddress, String> {
+    ) -> Result<MultisigAddress, Error> {
         if version != 1 {
-            Err("Unknown msig version".to_string())
+            Err(Error::Validation("Unknown msig version".to_string()))
         } else if threshold == 0 || addresses.is_empty() || threshold > addresses.len() as u8 {
-            Err("Invalid threshold".to_string())
+            Err(Error::Validation("Invalid threshold".to_string()))
         } else {
             Ok(MultisigAddress {
                 version,
@@ -92,6 +138,20 @@ impl MultisigAddress {
         bytes.copy_from_slice(&hashed);
         Address::new(bytes)
     }
+
+    /// Fails unless the keys hash to the expected address, e.g. before using a descriptor received
+    /// from a co-signer
+    pub fn check_address(&self, address: &Address) -> Result<(), Error> {
+        if self.address() == *address {
+            Ok(())
+        } else {
+            Err(Error::Validation(format!(
+                "Multisig descriptor hashes to {}, not {}",
+                self.address(),
+                address
+            )))
+        }
+    }
 }
 
 /// An Ed25519 Signature
@@ -108,6 +168,48 @@ pub struct MultisigSignature {
     pub version: u8,
 }
 
+impl MultisigSignature {
+    /// The multisig address of the keys of the subsigs
+    pub fn multisig_address(&self) -> MultisigAddress {
+        MultisigAddress {
+            version: self.version,
+            threshold: self.threshold,
+            public_keys: self.subsigs.iter().map(|subsig| subsig.key).collect(),
+        }
+    }
+
+    /// Verifies that the keys of the subsigs hash to `address` and that enough of them signed the
+    /// message to meet the threshold
+    pub fn verify(&self, address: &Address, message: &[u8]) -> Result<(), Error> {
+        if self.version != 1 {
+            return Err(Error::Verification("Unknown msig version".to_string()));
+        }
+        if self.threshold == 0 || self.threshold as usize > self.subsigs.len() {
+            return Err(Error::Verification("Invalid threshold".to_string()));
+        }
+        if self.multisig_address().address() != *address {
+            return Err(Error::Verification(format!(
+                "Multisig keys don't hash to {}",
+                address.encode_string()
+            )));
+        }
+        let mut signatures = 0;
+        for subsig in &self.subsigs {
+            if let Some(signature) = &subsig.sig {
+                Address(subsig.key.0).verify_signature(message, signature)?;
+                signatures += 1;
+            }
+        }
+        if signatures < self.threshold as usize {
+            return Err(Error::Verification(format!(
+                "Multisig has {} of the {} signatures it needs",
+                signatures, self.threshold
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Deserialize)]
 pub struct MultisigSubsig {
     #[serde(rename = "pk")]
@@ -115,3 +217,106 @@ pub struct MultisigSubsig {
     #[serde(rename = "s")]
     pub sig: Option<Signature>,
 }
+
+/// A logic signature, which authorizes transactions with a TEAL program instead of a key
+///
+/// Without a signature it is a contract account whose address is derived from the program. Signed
+/// by an account or multisig address it is a delegated signature for that address.
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize)]
+pub struct LogicSig {
+    #[serde(rename = "l", with = "serde_bytes")]
+    pub logic: Vec<u8>,
+    #[serde(
+        rename = "arg",
+        deserialize_with = "crate::util::deserialize_byte_vecs",
+        default
+    )]
+    pub args: Vec<Vec<u8>>,
+    #[serde(rename = "sig", default)]
+    pub sig: Option<Signature>,
+    #[serde(rename = "msig", default)]
+    pub msig: Option<MultisigSignature>,
+}
+
+impl LogicSig {
+    /// Create an unsigned logic signature from a compiled program and its arguments
+    pub fn new(logic: Vec<u8>, args: Vec<Vec<u8>>) -> LogicSig {
+        LogicSig {
+            logic,
+            args,
+            sig: None,
+            msig: None,
+        }
+    }
+
+    /// The program prefixed with "Program", the bytes that are hashed into the contract account
+    /// address and signed for delegation
+    pub fn bytes_to_sign(&self) -> Vec<u8> {
+        let mut prefix_encoded_program = b"Program".to_vec();
+        prefix_encoded_program.extend_from_slice(&self.logic);
+        prefix_encoded_program
+    }
+
+    /// The address of the contract account governed by the program
+    pub fn address(&self) -> Address {
+        let hashed = ChecksumAlg::digest(&self.bytes_to_sign());
+        let mut bytes = [0; HASH_LEN];
+        bytes.copy_from_slice(&hashed);
+        Address::new(bytes)
+    }
+
+    /// Whether the logic signature has been signed by an account or multisig address
+    pub fn is_delegated(&self) -> bool {
+        self.sig.is_some() || self.msig.is_some()
+    }
+
+    /// Verifies that the logic signature authorizes transactions of `address`, checking the
+    /// signature of a delegated one or the program hash of a contract account
+    ///
+    /// The program itself isn't evaluated, so the node may still reject the transactions
+    pub fn verify(&self, address: &Address) -> Result<(), Error> {
+        match (&self.sig, &self.msig) {
+            (Some(sig), None) => address.verify_signature(&self.bytes_to_sign(), sig),
+            (None, Some(msig)) => msig.verify(address, &self.bytes_to_sign()),
+            (None, None) if self.address() == *address => Ok(()),
+            (None, None) => Err(Error::Verification(format!(
+                "Logic sig program doesn't hash to {}",
+                address.encode_string()
+            ))),
+            (Some(_), Some(_)) => Err(Error::Verification(
+                "Logic signature can't have both a signature and a multisig signature".to_string(),
+            )),
+        }
+    }
+
+    /// Authorize the transaction with the logic signature
+    ///
+    /// An undelegated logic signature authorizes transactions from its contract account, a
+    /// delegated one authorizes transactions from the address that signed it. If the sender has
+    /// been rekeyed to a multisig or contract account the auth address is set accordingly.
+    pub fn sign_transaction(&self, transaction: &Transaction) -> Result<SignedTransaction, Error> {
+        if self.sig.is_some() && self.msig.is_some() {
+            return Err(Error::Validation(
+                "Logic signature can't have both a signature and a multisig signature".to_string(),
+            ));
+        }
+        Ok(SignedTransaction {
+            logic_sig: Some(self.clone()),
+            multisig: None,
+            auth_address: transaction.auth_address(self.authorizer(transaction)),
+            sig: None,
+            transaction: transaction.clone(),
+            transaction_id: transaction.id()?,
+        })
+    }
+
+    // The address the logic signature authorizes `transaction` for
+    pub(crate) fn authorizer(&self, transaction: &Transaction) -> Address {
+        match &self.msig {
+            Some(msig) => msig.multisig_address().address(),
+            // The key behind a single signature can't be recovered, so it must be the sender's
+            None if self.sig.is_some() => transaction.sender,
+            None => self.address(),
+        }
+    }
+}
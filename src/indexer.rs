@@ -1,15 +1,30 @@
+#[cfg(feature = "async")]
+use futures::future::BoxFuture;
+#[cfg(feature = "async")]
+use futures::stream::BoxStream;
 use reqwest::header::HeaderMap;
 
-use crate::algod::models::{ NodeStatus};
-use crate::{Error};
+use crate::indexer::models::{
+    Account, AccountResponse, AccountsResponse, Application, ApplicationResponse,
+    ApplicationsResponse, Asset, AssetBalancesResponse, AssetResponse, AssetsResponse, Block,
+    HealthCheck, MiniAssetHolding, Transaction, TransactionResponse, TransactionsResponse,
+};
+use crate::indexer::queries::{
+    AccountQuery, ApplicationQuery, AssetQuery, BalanceQuery, PagedQuery, TransactionQuery,
+};
+#[cfg(feature = "async")]
+use crate::transport::{AsyncHttpTransport, AsyncReqwestTransport};
+use crate::transport::{header_pairs, HttpRequest, HttpTransport, Method, ReqwestTransport};
+use crate::{Error, Round};
 
 const AUTH_HEADER: &str = "X-Indexer-API-Token";
 
-/// Client for interacting with the Algorand protocol daemon
+/// Client for searching the history of the chain through the Algorand indexer
 pub struct IndexerClient {
     url: String,
     token: String,
-    headers: HeaderMap,
+    headers: Vec<(String, String)>,
+    transport: Box<dyn HttpTransport>,
 }
 
 impl IndexerClient {
@@ -18,42 +33,2215 @@ impl IndexerClient {
     }
 
     pub fn new_with_headers(address: &str, token: &str, headers: HeaderMap) -> IndexerClient {
+        IndexerClient::new_with_transport(address, token, headers, ReqwestTransport::new())
+    }
+
+    /// Creates a client sending its requests, with the token and custom headers, through the given
+    /// transport
+    pub fn new_with_transport<T>(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+        transport: T,
+    ) -> IndexerClient
+    where
+        T: HttpTransport + 'static,
+    {
         IndexerClient {
             url: address.to_string(),
             token: token.to_string(),
+            headers: header_pairs(&headers),
+            transport: Box::new(transport),
+        }
+    }
+
+    /// Gets the health of the indexer, including the round it has caught up to
+    pub fn health(&self) -> Result<HealthCheck, Error> {
+        let request = self.request(Method::Get, format!("{}/health", self.url));
+        self.transport.send(request)?.json()
+    }
+
+    /// Searches for accounts matching the query
+    pub fn search_accounts(&self, query: &AccountQuery) -> Result<AccountsResponse, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/accounts", self.url))
+            .query(query)?;
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets the information of an account at the latest round
+    pub fn lookup_account(&self, address: &str) -> Result<AccountResponse, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/accounts/{}", self.url, address));
+        self.transport.send(request)?.json()
+    }
+
+    /// Searches for transactions involving an account, the address of the query is ignored
+    pub fn lookup_account_transactions(
+        &self,
+        address: &str,
+        query: &TransactionQuery,
+    ) -> Result<TransactionsResponse, Error> {
+        let request = self
+            .request(
+                Method::Get,
+                format!("{}/v2/accounts/{}/transactions", self.url, address),
+            )
+            .query(query)?;
+        self.transport.send(request)?.json()
+    }
+
+    /// Searches for transactions matching the query
+    pub fn search_transactions(
+        &self,
+        query: &TransactionQuery,
+    ) -> Result<TransactionsResponse, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/transactions", self.url))
+            .query(query)?;
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets a confirmed transaction by its id
+    pub fn lookup_transaction(&self, transaction_id: &str) -> Result<TransactionResponse, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/transactions/{}", self.url, transaction_id),
+        );
+        self.transport.send(request)?.json()
+    }
+
+    /// Searches for assets matching the query
+    pub fn search_assets(&self, query: &AssetQuery) -> Result<AssetsResponse, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/assets", self.url))
+            .query(query)?;
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets the params of an asset
+    pub fn lookup_asset(&self, asset_id: u64) -> Result<AssetResponse, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/assets/{}", self.url, asset_id));
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets the accounts holding an asset and their balances
+    pub fn lookup_asset_balances(
+        &self,
+        asset_id: u64,
+        query: &BalanceQuery,
+    ) -> Result<AssetBalancesResponse, Error> {
+        let request = self
+            .request(
+                Method::Get,
+                format!("{}/v2/assets/{}/balances", self.url, asset_id),
+            )
+            .query(query)?;
+        self.transport.send(request)?.json()
+    }
+
+    /// Searches for applications matching the query
+    pub fn search_applications(
+        &self,
+        query: &ApplicationQuery,
+    ) -> Result<ApplicationsResponse, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/applications", self.url))
+            .query(query)?;
+        self.transport.send(request)?.json()
+    }
+
+    /// Gets the params and global state of an application
+    pub fn lookup_application(&self, application_id: u64) -> Result<ApplicationResponse, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/applications/{}", self.url, application_id),
+        );
+        self.transport.send(request)?.json()
+    }
+
+    /// Iterates over all the accounts matching the query, following the pages of results
+    pub fn search_accounts_paginated(&self, query: &AccountQuery) -> Paginator<'_, Account> {
+        let query = query.clone();
+        Paginator::new(move |next, limit| {
+            let response = self.search_accounts(&query.page(next, limit))?;
+            Ok((response.accounts, response.next_token))
+        })
+    }
+
+    /// Iterates over all the transactions involving an account, following the pages of results
+    pub fn lookup_account_transactions_paginated<'a>(
+        &'a self,
+        address: &'a str,
+        query: &TransactionQuery,
+    ) -> Paginator<'a, Transaction> {
+        let query = query.clone();
+        Paginator::new(move |next, limit| {
+            let response = self.lookup_account_transactions(address, &query.page(next, limit))?;
+            Ok((response.transactions, response.next_token))
+        })
+    }
+
+    /// Iterates over all the transactions matching the query, following the pages of results
+    pub fn search_transactions_paginated(
+        &self,
+        query: &TransactionQuery,
+    ) -> Paginator<'_, Transaction> {
+        let query = query.clone();
+        Paginator::new(move |next, limit| {
+            let response = self.search_transactions(&query.page(next, limit))?;
+            Ok((response.transactions, response.next_token))
+        })
+    }
+
+    /// Iterates over all the assets matching the query, following the pages of results
+    pub fn search_assets_paginated(&self, query: &AssetQuery) -> Paginator<'_, Asset> {
+        let query = query.clone();
+        Paginator::new(move |next, limit| {
+            let response = self.search_assets(&query.page(next, limit))?;
+            Ok((response.assets, response.next_token))
+        })
+    }
+
+    /// Iterates over all the holders of an asset, following the pages of results
+    pub fn lookup_asset_balances_paginated(
+        &self,
+        asset_id: u64,
+        query: &BalanceQuery,
+    ) -> Paginator<'_, MiniAssetHolding> {
+        let query = query.clone();
+        Paginator::new(move |next, limit| {
+            let response = self.lookup_asset_balances(asset_id, &query.page(next, limit))?;
+            Ok((response.balances, response.next_token))
+        })
+    }
+
+    /// Iterates over all the applications matching the query, following the pages of results
+    pub fn search_applications_paginated(
+        &self,
+        query: &ApplicationQuery,
+    ) -> Paginator<'_, Application> {
+        let query = query.clone();
+        Paginator::new(move |next, limit| {
+            let response = self.search_applications(&query.page(next, limit))?;
+            Ok((response.applications, response.next_token))
+        })
+    }
+
+    /// Gets the block for the given round, including its transactions
+    pub fn lookup_block(&self, round: Round) -> Result<Block, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/blocks/{}", self.url, round.0));
+        self.transport.send(request)?.json()
+    }
+
+    fn request(&self, method: Method, url: String) -> HttpRequest {
+        HttpRequest::new(method, url)
+            .header(AUTH_HEADER, &self.token)
+            .headers(&self.headers)
+    }
+}
+
+/// Async version of [IndexerClient], available with the `async` feature
+#[cfg(feature = "async")]
+pub struct AsyncIndexerClient {
+    url: String,
+    token: String,
+    headers: Vec<(String, String)>,
+    transport: Box<dyn AsyncHttpTransport>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncIndexerClient {
+    pub fn new(address: &str, token: &str) -> AsyncIndexerClient {
+        AsyncIndexerClient::new_with_headers(
+            address,
+            token,
+            HeaderMap::new(),
+        )
+    }
+
+    pub fn new_with_headers(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+    ) -> AsyncIndexerClient {
+        AsyncIndexerClient::new_with_transport(
+            address,
+            token,
             headers,
+            AsyncReqwestTransport::new(),
+        )
+    }
+
+    /// Creates a client sending its requests, with the token and custom headers, through the given
+    /// transport
+    pub fn new_with_transport<T>(
+        address: &str,
+        token: &str,
+        headers: HeaderMap,
+        transport: T,
+    ) -> AsyncIndexerClient
+    where
+        T: AsyncHttpTransport + 'static,
+    {
+        AsyncIndexerClient {
+            url: address.to_string(),
+            token: token.to_string(),
+            headers: header_pairs(&headers),
+            transport: Box::new(transport),
         }
     }
-    /// Returns Ok if healthy
-    pub fn health(&self) -> Result<(), Error> {
-        let _ = reqwest::Client::new()
-            .get(&format!("{}/health", self.url))
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?;
-        Ok(())
+
+    /// Gets the health of the indexer, including the round it has caught up to
+    pub async fn health(&self) -> Result<HealthCheck, Error> {
+        let request = self.request(Method::Get, format!("{}/health", self.url));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Searches for accounts matching the query
+    pub async fn search_accounts(&self, query: &AccountQuery) -> Result<AccountsResponse, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/accounts", self.url))
+            .query(query)?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the information of an account at the latest round
+    pub async fn lookup_account(&self, address: &str) -> Result<AccountResponse, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/accounts/{}", self.url, address));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Searches for transactions involving an account, the address of the query is ignored
+    pub async fn lookup_account_transactions(
+        &self,
+        address: &str,
+        query: &TransactionQuery,
+    ) -> Result<TransactionsResponse, Error> {
+        let request = self
+            .request(
+                Method::Get,
+                format!("{}/v2/accounts/{}/transactions", self.url, address),
+            )
+            .query(query)?;
+        self.transport.send(request).await?.json()
     }
-    /// Gets the current node status
-    pub fn status(&self) -> Result<NodeStatus, Error> {
-        let response = reqwest::Client::new()
-            .get(&format!("{}/v2/status", self.url))
+
+    /// Searches for transactions matching the query
+    pub async fn search_transactions(
+        &self,
+        query: &TransactionQuery,
+    ) -> Result<TransactionsResponse, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/transactions", self.url))
+            .query(query)?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets a confirmed transaction by its id
+    pub async fn lookup_transaction(
+        &self,
+        transaction_id: &str,
+    ) -> Result<TransactionResponse, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/transactions/{}", self.url, transaction_id),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Searches for assets matching the query
+    pub async fn search_assets(&self, query: &AssetQuery) -> Result<AssetsResponse, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/assets", self.url))
+            .query(query)?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the params of an asset
+    pub async fn lookup_asset(&self, asset_id: u64) -> Result<AssetResponse, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/assets/{}", self.url, asset_id));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the accounts holding an asset and their balances
+    pub async fn lookup_asset_balances(
+        &self,
+        asset_id: u64,
+        query: &BalanceQuery,
+    ) -> Result<AssetBalancesResponse, Error> {
+        let request = self
+            .request(
+                Method::Get,
+                format!("{}/v2/assets/{}/balances", self.url, asset_id),
+            )
+            .query(query)?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Searches for applications matching the query
+    pub async fn search_applications(
+        &self,
+        query: &ApplicationQuery,
+    ) -> Result<ApplicationsResponse, Error> {
+        let request = self
+            .request(Method::Get, format!("{}/v2/applications", self.url))
+            .query(query)?;
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the params and global state of an application
+    pub async fn lookup_application(
+        &self,
+        application_id: u64,
+    ) -> Result<ApplicationResponse, Error> {
+        let request = self.request(
+            Method::Get,
+            format!("{}/v2/applications/{}", self.url, application_id),
+        );
+        self.transport.send(request).await?.json()
+    }
+
+    /// Gets the block for the given round, including its transactions
+    pub async fn lookup_block(&self, round: Round) -> Result<Block, Error> {
+        let request = self.request(Method::Get, format!("{}/v2/blocks/{}", self.url, round.0));
+        self.transport.send(request).await?.json()
+    }
+
+    /// Streams all the accounts matching the query, following the pages of results
+    pub fn search_accounts_paginated(&self, query: &AccountQuery) -> AsyncPaginator<'_, Account> {
+        let query = query.clone();
+        AsyncPaginator::new(move |next, limit| {
+            let query = query.page(next.as_deref(), limit);
+            Box::pin(async move {
+                let response = self.search_accounts(&query).await?;
+                Ok((response.accounts, response.next_token))
+            })
+        })
+    }
+
+    /// Streams all the transactions involving an account, following the pages of results
+    pub fn lookup_account_transactions_paginated<'a>(
+        &'a self,
+        address: &'a str,
+        query: &TransactionQuery,
+    ) -> AsyncPaginator<'a, Transaction> {
+        let query = query.clone();
+        AsyncPaginator::new(move |next, limit| {
+            let query = query.page(next.as_deref(), limit);
+            Box::pin(async move {
+                let response = self.lookup_account_transactions(address, &query).await?;
+                Ok((response.transactions, response.next_token))
+            })
+        })
+    }
+
+    /// Streams all the transactions matching the query, following the pages of results
+    pub fn search_transactions_paginated(
+        &self,
+        query: &TransactionQuery,
+    ) -> AsyncPaginator<'_, Transaction> {
+        let query = query.clone();
+        AsyncPaginator::new(move |next, limit| {
+            let query = query.page(next.as_deref(), limit);
+            Box::pin(async move {
+                let response = self.search_transactions(&query).await?;
+                Ok((response.transactions, response.next_token))
+            })
+        })
+    }
+
+    /// Streams all the assets matching the query, following the pages of results
+    pub fn search_assets_paginated(&self, query: &AssetQuery) -> AsyncPaginator<'_, Asset> {
+        let query = query.clone();
+        AsyncPaginator::new(move |next, limit| {
+            let query = query.page(next.as_deref(), limit);
+            Box::pin(async move {
+                let response = self.search_assets(&query).await?;
+                Ok((response.assets, response.next_token))
+            })
+        })
+    }
+
+    /// Streams all the holders of an asset, following the pages of results
+    pub fn lookup_asset_balances_paginated(
+        &self,
+        asset_id: u64,
+        query: &BalanceQuery,
+    ) -> AsyncPaginator<'_, MiniAssetHolding> {
+        let query = query.clone();
+        AsyncPaginator::new(move |next, limit| {
+            let query = query.page(next.as_deref(), limit);
+            Box::pin(async move {
+                let response = self.lookup_asset_balances(asset_id, &query).await?;
+                Ok((response.balances, response.next_token))
+            })
+        })
+    }
+
+    /// Streams all the applications matching the query, following the pages of results
+    pub fn search_applications_paginated(
+        &self,
+        query: &ApplicationQuery,
+    ) -> AsyncPaginator<'_, Application> {
+        let query = query.clone();
+        AsyncPaginator::new(move |next, limit| {
+            let query = query.page(next.as_deref(), limit);
+            Box::pin(async move {
+                let response = self.search_applications(&query).await?;
+                Ok((response.applications, response.next_token))
+            })
+        })
+    }
+
+    fn request(&self, method: Method, url: String) -> HttpRequest {
+        HttpRequest::new(method, url)
             .header(AUTH_HEADER, &self.token)
-            .headers(self.headers.clone())
-            .send()?
-            .error_for_status()?
-            .json()?;
-        Ok(response)
+            .headers(&self.headers)
     }
 }
 
-pub mod requests {
-    use reqwest::Method;
-    use serde::de::DeserializeOwned;
+type FetchPage<'a, T> =
+    Box<dyn Fn(Option<&str>, Option<u64>) -> Result<(Vec<T>, Option<String>), Error> + 'a>;
+
+/// The results of a search, fetched a page at a time as they are iterated over
+///
+/// Each page is requested with the `next-token` of the previous one until the last page or the
+/// maximum number of items is reached. An error ends the iteration after being returned. The
+/// pages are fetched with the blocking client, the paginators of `AsyncIndexerClient` stream the
+/// results without blocking.
+pub struct Paginator<'a, T> {
+    fetch: FetchPage<'a, T>,
+    page_size: Option<u64>,
+    max_items: Option<u64>,
+}
+
+impl<'a, T> Paginator<'a, T> {
+    fn new<F>(fetch: F) -> Paginator<'a, T>
+    where
+        F: Fn(Option<&str>, Option<u64>) -> Result<(Vec<T>, Option<String>), Error> + 'a,
+    {
+        Paginator {
+            fetch: Box::new(fetch),
+            page_size: None,
+            max_items: None,
+        }
+    }
+
+    /// The number of results to request per page, overriding the limit of the query
+    pub fn page_size(mut self, page_size: u64) -> Paginator<'a, T> {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// The maximum number of results to return across all pages
+    pub fn max_items(mut self, max_items: u64) -> Paginator<'a, T> {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Iterates over the results, starting from the first page
+    pub fn iter(&self) -> PaginatorIter<'_, 'a, T> {
+        PaginatorIter {
+            paginator: self,
+            cursor: PageCursor::new(),
+        }
+    }
+}
+
+impl<'p, 'a, T> IntoIterator for &'p Paginator<'a, T> {
+    type Item = Result<T, Error>;
+    type IntoIter = PaginatorIter<'p, 'a, T>;
+
+    fn into_iter(self) -> PaginatorIter<'p, 'a, T> {
+        self.iter()
+    }
+}
+
+/// Iterator over the results of a `Paginator`
+pub struct PaginatorIter<'p, 'a, T> {
+    paginator: &'p Paginator<'a, T>,
+    cursor: PageCursor<T>,
+}
+
+impl<'p, 'a, T> Iterator for PaginatorIter<'p, 'a, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Result<T, Error>> {
+        loop {
+            match self
+                .cursor
+                .step(self.paginator.page_size, self.paginator.max_items)
+            {
+                PageStep::Item(item) => return Some(Ok(item)),
+                PageStep::Done => return None,
+                PageStep::Fetch(limit) => {
+                    let page = (self.paginator.fetch)(self.cursor.next_token.as_deref(), limit);
+                    if let Err(error) = self.cursor.receive(page) {
+                        return Some(Err(error));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What a paginator has to do next to return a result
+enum PageStep<T> {
+    Item(T),
+    /// Fetch the next page, with the limit to request
+    Fetch(Option<u64>),
+    Done,
+}
+
+/// The position of a paginator in the pages of results
+struct PageCursor<T> {
+    page: std::vec::IntoIter<T>,
+    next_token: Option<String>,
+    returned: u64,
+    done: bool,
+}
+
+impl<T> PageCursor<T> {
+    fn new() -> PageCursor<T> {
+        PageCursor {
+            page: Vec::new().into_iter(),
+            next_token: None,
+            returned: 0,
+            done: false,
+        }
+    }
+
+    fn step(&mut self, page_size: Option<u64>, max_items: Option<u64>) -> PageStep<T> {
+        let remaining = max_items.map(|max| max - max.min(self.returned));
+        if remaining == Some(0) {
+            return PageStep::Done;
+        }
+        if let Some(item) = self.page.next() {
+            self.returned += 1;
+            return PageStep::Item(item);
+        }
+        if self.done {
+            return PageStep::Done;
+        }
+        // Don't request more results than are left to return
+        PageStep::Fetch(match (page_size, remaining) {
+            (Some(page_size), Some(remaining)) => Some(page_size.min(remaining)),
+            (page_size, _) => page_size,
+        })
+    }
+
+    fn receive(&mut self, page: Result<(Vec<T>, Option<String>), Error>) -> Result<(), Error> {
+        match page {
+            Ok((items, next_token)) => {
+                self.done = items.is_empty() || next_token.is_none();
+                self.next_token = next_token;
+                self.page = items.into_iter();
+                Ok(())
+            }
+            Err(error) => {
+                self.done = true;
+                Err(error)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+type FetchPageAsync<'a, T> = Box<
+    dyn Fn(Option<String>, Option<u64>) -> BoxFuture<'a, Result<(Vec<T>, Option<String>), Error>>
+        + Send
+        + Sync
+        + 'a,
+>;
+
+/// The results of a search, fetched a page at a time as they are streamed, available with the
+/// `async` feature
+///
+/// Each page is requested with the `next-token` of the previous one until the last page or the
+/// maximum number of items is reached. An error ends the stream after being returned.
+#[cfg(feature = "async")]
+pub struct AsyncPaginator<'a, T> {
+    fetch: FetchPageAsync<'a, T>,
+    page_size: Option<u64>,
+    max_items: Option<u64>,
+}
+
+#[cfg(feature = "async")]
+impl<'a, T> AsyncPaginator<'a, T> {
+    fn new<F>(fetch: F) -> AsyncPaginator<'a, T>
+    where
+        F: Fn(
+                Option<String>,
+                Option<u64>,
+            ) -> BoxFuture<'a, Result<(Vec<T>, Option<String>), Error>>
+            + Send
+            + Sync
+            + 'a,
+    {
+        AsyncPaginator {
+            fetch: Box::new(fetch),
+            page_size: None,
+            max_items: None,
+        }
+    }
+
+    /// The number of results to request per page, overriding the limit of the query
+    pub fn page_size(mut self, page_size: u64) -> AsyncPaginator<'a, T> {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// The maximum number of results to return across all pages
+    pub fn max_items(mut self, max_items: u64) -> AsyncPaginator<'a, T> {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Streams the results, starting from the first page
+    pub fn stream(&self) -> BoxStream<'_, Result<T, Error>>
+    where
+        T: Send,
+    {
+        Box::pin(futures::stream::unfold(
+            PageCursor::new(),
+            move |mut cursor| async move {
+                loop {
+                    match cursor.step(self.page_size, self.max_items) {
+                        PageStep::Item(item) => return Some((Ok(item), cursor)),
+                        PageStep::Done => return None,
+                        PageStep::Fetch(limit) => {
+                            let page = (self.fetch)(cursor.next_token.clone(), limit).await;
+                            if let Err(error) = cursor.receive(page) {
+                                return Some((Err(error), cursor));
+                            }
+                        }
+                    }
+                }
+            },
+        ))
+    }
+}
+
+/// Builders for the filters of the indexer search endpoints
+///
+/// Every filter is optional, unset filters are left out of the request.
+pub mod queries {
+    use data_encoding::BASE64;
     use serde::Serialize;
 
-    pub trait APIV1Request: Serialize {
-        type Response: DeserializeOwned;
-        const PATH: &'static str;
-        const METHOD: Method;
+    use crate::crypto::Address;
+    use crate::Round;
+
+    /// A query of an endpoint returning its results a page at a time
+    pub(crate) trait PagedQuery: Sized {
+        /// The query for the page of results after `next`, or the query itself if unset
+        fn page(&self, next: Option<&str>, limit: Option<u64>) -> Self;
+    }
+
+    /// The role an address has in the transactions to search for
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+    pub enum AddressRole {
+        #[serde(rename = "sender")]
+        Sender,
+        #[serde(rename = "receiver")]
+        Receiver,
+        #[serde(rename = "freeze-target")]
+        FreezeTarget,
+    }
+
+    /// The type of the transactions to search for
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+    pub enum TxType {
+        #[serde(rename = "pay")]
+        Payment,
+        #[serde(rename = "keyreg")]
+        KeyRegistration,
+        #[serde(rename = "acfg")]
+        AssetConfig,
+        #[serde(rename = "axfer")]
+        AssetTransfer,
+        #[serde(rename = "afrz")]
+        AssetFreeze,
+        #[serde(rename = "appl")]
+        ApplicationCall,
+    }
+
+    /// The kind of signature of the transactions to search for
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+    pub enum SigType {
+        #[serde(rename = "sig")]
+        Sig,
+        #[serde(rename = "msig")]
+        Msig,
+        #[serde(rename = "lsig")]
+        Lsig,
+    }
+
+    /// Filters for searching accounts
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct AccountQuery {
+        #[serde(rename = "application-id", skip_serializing_if = "Option::is_none")]
+        application_id: Option<u64>,
+        #[serde(rename = "asset-id", skip_serializing_if = "Option::is_none")]
+        asset_id: Option<u64>,
+        #[serde(rename = "auth-addr", skip_serializing_if = "Option::is_none")]
+        auth_addr: Option<String>,
+        #[serde(
+            rename = "currency-greater-than",
+            skip_serializing_if = "Option::is_none"
+        )]
+        currency_greater_than: Option<u64>,
+        #[serde(rename = "currency-less-than", skip_serializing_if = "Option::is_none")]
+        currency_less_than: Option<u64>,
+        #[serde(rename = "include-all", skip_serializing_if = "Option::is_none")]
+        include_all: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limit: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        next: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        round: Option<u64>,
+    }
+
+    impl PagedQuery for AccountQuery {
+        fn page(&self, next: Option<&str>, limit: Option<u64>) -> AccountQuery {
+            let mut query = self.clone();
+            if next.is_some() {
+                query.next = next.map(str::to_string);
+            }
+            if limit.is_some() {
+                query.limit = limit;
+            }
+            query
+        }
+    }
+
+    impl AccountQuery {
+        pub fn new() -> AccountQuery {
+            AccountQuery::default()
+        }
+
+        /// Only accounts opted in to the application
+        pub fn application_id(mut self, application_id: u64) -> AccountQuery {
+            self.application_id = Some(application_id);
+            self
+        }
+
+        /// Only accounts holding the asset, the currency filters then apply to the asset
+        pub fn asset_id(mut self, asset_id: u64) -> AccountQuery {
+            self.asset_id = Some(asset_id);
+            self
+        }
+
+        /// Only accounts rekeyed to the address
+        pub fn auth_addr(mut self, auth_addr: &Address) -> AccountQuery {
+            self.auth_addr = Some(auth_addr.encode_string());
+            self
+        }
+
+        /// Only accounts with a balance greater than the amount
+        pub fn currency_greater_than(mut self, amount: u64) -> AccountQuery {
+            self.currency_greater_than = Some(amount);
+            self
+        }
+
+        /// Only accounts with a balance less than the amount
+        pub fn currency_less_than(mut self, amount: u64) -> AccountQuery {
+            self.currency_less_than = Some(amount);
+            self
+        }
+
+        /// Include closed accounts and deleted assets and applications
+        pub fn include_all(mut self, include_all: bool) -> AccountQuery {
+            self.include_all = Some(include_all);
+            self
+        }
+
+        /// The maximum number of results in a page
+        pub fn limit(mut self, limit: u64) -> AccountQuery {
+            self.limit = Some(limit);
+            self
+        }
+
+        /// The token of the page to get, from the `next_token` of the previous page
+        pub fn next(mut self, next: &str) -> AccountQuery {
+            self.next = Some(next.to_string());
+            self
+        }
+
+        /// The accounts as of the round
+        pub fn round(mut self, round: Round) -> AccountQuery {
+            self.round = Some(round.0);
+            self
+        }
+    }
+
+    /// Filters for searching transactions
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct TransactionQuery {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        address: Option<String>,
+        #[serde(rename = "address-role", skip_serializing_if = "Option::is_none")]
+        address_role: Option<AddressRole>,
+        #[serde(rename = "after-time", skip_serializing_if = "Option::is_none")]
+        after_time: Option<String>,
+        #[serde(rename = "application-id", skip_serializing_if = "Option::is_none")]
+        application_id: Option<u64>,
+        #[serde(rename = "asset-id", skip_serializing_if = "Option::is_none")]
+        asset_id: Option<u64>,
+        #[serde(rename = "before-time", skip_serializing_if = "Option::is_none")]
+        before_time: Option<String>,
+        #[serde(
+            rename = "currency-greater-than",
+            skip_serializing_if = "Option::is_none"
+        )]
+        currency_greater_than: Option<u64>,
+        #[serde(rename = "currency-less-than", skip_serializing_if = "Option::is_none")]
+        currency_less_than: Option<u64>,
+        #[serde(rename = "exclude-close-to", skip_serializing_if = "Option::is_none")]
+        exclude_close_to: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limit: Option<u64>,
+        #[serde(rename = "max-round", skip_serializing_if = "Option::is_none")]
+        max_round: Option<u64>,
+        #[serde(rename = "min-round", skip_serializing_if = "Option::is_none")]
+        min_round: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        next: Option<String>,
+        #[serde(rename = "note-prefix", skip_serializing_if = "Option::is_none")]
+        note_prefix: Option<String>,
+        #[serde(rename = "rekey-to", skip_serializing_if = "Option::is_none")]
+        rekey_to: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        round: Option<u64>,
+        #[serde(rename = "sig-type", skip_serializing_if = "Option::is_none")]
+        sig_type: Option<SigType>,
+        #[serde(rename = "tx-type", skip_serializing_if = "Option::is_none")]
+        tx_type: Option<TxType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        txid: Option<String>,
+    }
+
+    impl PagedQuery for TransactionQuery {
+        fn page(&self, next: Option<&str>, limit: Option<u64>) -> TransactionQuery {
+            let mut query = self.clone();
+            if next.is_some() {
+                query.next = next.map(str::to_string);
+            }
+            if limit.is_some() {
+                query.limit = limit;
+            }
+            query
+        }
+    }
+
+    impl TransactionQuery {
+        pub fn new() -> TransactionQuery {
+            TransactionQuery::default()
+        }
+
+        /// Only transactions involving the address, see `address_role`
+        pub fn address(mut self, address: &Address) -> TransactionQuery {
+            self.address = Some(address.encode_string());
+            self
+        }
+
+        /// Only transactions where the address has the given role
+        pub fn address_role(mut self, address_role: AddressRole) -> TransactionQuery {
+            self.address_role = Some(address_role);
+            self
+        }
+
+        /// Only transactions confirmed after the time, in RFC 3339 format
+        pub fn after_time(mut self, after_time: &str) -> TransactionQuery {
+            self.after_time = Some(after_time.to_string());
+            self
+        }
+
+        /// Only calls to the application
+        pub fn application_id(mut self, application_id: u64) -> TransactionQuery {
+            self.application_id = Some(application_id);
+            self
+        }
+
+        /// Only transactions of the asset, the currency filters then apply to the asset
+        pub fn asset_id(mut self, asset_id: u64) -> TransactionQuery {
+            self.asset_id = Some(asset_id);
+            self
+        }
+
+        /// Only transactions confirmed before the time, in RFC 3339 format
+        pub fn before_time(mut self, before_time: &str) -> TransactionQuery {
+            self.before_time = Some(before_time.to_string());
+            self
+        }
+
+        /// Only transactions transferring more than the amount
+        pub fn currency_greater_than(mut self, amount: u64) -> TransactionQuery {
+            self.currency_greater_than = Some(amount);
+            self
+        }
+
+        /// Only transactions transferring less than the amount
+        pub fn currency_less_than(mut self, amount: u64) -> TransactionQuery {
+            self.currency_less_than = Some(amount);
+            self
+        }
+
+        /// Leave out transactions where the address only appears as the close to address
+        pub fn exclude_close_to(mut self, exclude_close_to: bool) -> TransactionQuery {
+            self.exclude_close_to = Some(exclude_close_to);
+            self
+        }
+
+        /// The maximum number of results in a page
+        pub fn limit(mut self, limit: u64) -> TransactionQuery {
+            self.limit = Some(limit);
+            self
+        }
+
+        /// Only transactions confirmed at or before the round
+        pub fn max_round(mut self, max_round: Round) -> TransactionQuery {
+            self.max_round = Some(max_round.0);
+            self
+        }
+
+        /// Only transactions confirmed at or after the round
+        pub fn min_round(mut self, min_round: Round) -> TransactionQuery {
+            self.min_round = Some(min_round.0);
+            self
+        }
+
+        /// The token of the page to get, from the `next_token` of the previous page
+        pub fn next(mut self, next: &str) -> TransactionQuery {
+            self.next = Some(next.to_string());
+            self
+        }
+
+        /// Only transactions whose note starts with the prefix
+        pub fn note_prefix(mut self, note_prefix: &[u8]) -> TransactionQuery {
+            self.note_prefix = Some(BASE64.encode(note_prefix));
+            self
+        }
+
+        /// Only transactions which rekey the sender
+        pub fn rekey_to(mut self, rekey_to: bool) -> TransactionQuery {
+            self.rekey_to = Some(rekey_to);
+            self
+        }
+
+        /// Only transactions confirmed in the round
+        pub fn round(mut self, round: Round) -> TransactionQuery {
+            self.round = Some(round.0);
+            self
+        }
+
+        /// Only transactions with the kind of signature
+        pub fn sig_type(mut self, sig_type: SigType) -> TransactionQuery {
+            self.sig_type = Some(sig_type);
+            self
+        }
+
+        /// Only transactions of the type
+        pub fn tx_type(mut self, tx_type: TxType) -> TransactionQuery {
+            self.tx_type = Some(tx_type);
+            self
+        }
+
+        /// Only the transaction with the id
+        pub fn txid(mut self, txid: &str) -> TransactionQuery {
+            self.txid = Some(txid.to_string());
+            self
+        }
+    }
+
+    /// Filters for searching assets
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct AssetQuery {
+        #[serde(rename = "asset-id", skip_serializing_if = "Option::is_none")]
+        asset_id: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        creator: Option<String>,
+        #[serde(rename = "include-all", skip_serializing_if = "Option::is_none")]
+        include_all: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limit: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        next: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unit: Option<String>,
+    }
+
+    impl PagedQuery for AssetQuery {
+        fn page(&self, next: Option<&str>, limit: Option<u64>) -> AssetQuery {
+            let mut query = self.clone();
+            if next.is_some() {
+                query.next = next.map(str::to_string);
+            }
+            if limit.is_some() {
+                query.limit = limit;
+            }
+            query
+        }
+    }
+
+    impl AssetQuery {
+        pub fn new() -> AssetQuery {
+            AssetQuery::default()
+        }
+
+        pub fn asset_id(mut self, asset_id: u64) -> AssetQuery {
+            self.asset_id = Some(asset_id);
+            self
+        }
+
+        /// Only assets created by the address
+        pub fn creator(mut self, creator: &Address) -> AssetQuery {
+            self.creator = Some(creator.encode_string());
+            self
+        }
+
+        /// Include destroyed assets
+        pub fn include_all(mut self, include_all: bool) -> AssetQuery {
+            self.include_all = Some(include_all);
+            self
+        }
+
+        /// The maximum number of results in a page
+        pub fn limit(mut self, limit: u64) -> AssetQuery {
+            self.limit = Some(limit);
+            self
+        }
+
+        /// Only assets whose name contains the string, ignoring case
+        pub fn name(mut self, name: &str) -> AssetQuery {
+            self.name = Some(name.to_string());
+            self
+        }
+
+        /// The token of the page to get, from the `next_token` of the previous page
+        pub fn next(mut self, next: &str) -> AssetQuery {
+            self.next = Some(next.to_string());
+            self
+        }
+
+        /// Only assets whose unit name contains the string, ignoring case
+        pub fn unit(mut self, unit: &str) -> AssetQuery {
+            self.unit = Some(unit.to_string());
+            self
+        }
+    }
+
+    /// Filters for looking up the holders of an asset
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct BalanceQuery {
+        #[serde(
+            rename = "currency-greater-than",
+            skip_serializing_if = "Option::is_none"
+        )]
+        currency_greater_than: Option<u64>,
+        #[serde(rename = "currency-less-than", skip_serializing_if = "Option::is_none")]
+        currency_less_than: Option<u64>,
+        #[serde(rename = "include-all", skip_serializing_if = "Option::is_none")]
+        include_all: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limit: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        next: Option<String>,
+    }
+
+    impl PagedQuery for BalanceQuery {
+        fn page(&self, next: Option<&str>, limit: Option<u64>) -> BalanceQuery {
+            let mut query = self.clone();
+            if next.is_some() {
+                query.next = next.map(str::to_string);
+            }
+            if limit.is_some() {
+                query.limit = limit;
+            }
+            query
+        }
+    }
+
+    impl BalanceQuery {
+        pub fn new() -> BalanceQuery {
+            BalanceQuery::default()
+        }
+
+        /// Only holdings greater than the amount
+        pub fn currency_greater_than(mut self, amount: u64) -> BalanceQuery {
+            self.currency_greater_than = Some(amount);
+            self
+        }
+
+        /// Only holdings less than the amount
+        pub fn currency_less_than(mut self, amount: u64) -> BalanceQuery {
+            self.currency_less_than = Some(amount);
+            self
+        }
+
+        /// Include holdings that have been closed out
+        pub fn include_all(mut self, include_all: bool) -> BalanceQuery {
+            self.include_all = Some(include_all);
+            self
+        }
+
+        /// The maximum number of results in a page
+        pub fn limit(mut self, limit: u64) -> BalanceQuery {
+            self.limit = Some(limit);
+            self
+        }
+
+        /// The token of the page to get, from the `next_token` of the previous page
+        pub fn next(mut self, next: &str) -> BalanceQuery {
+            self.next = Some(next.to_string());
+            self
+        }
+    }
+
+    /// Filters for searching applications
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct ApplicationQuery {
+        #[serde(rename = "application-id", skip_serializing_if = "Option::is_none")]
+        application_id: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        creator: Option<String>,
+        #[serde(rename = "include-all", skip_serializing_if = "Option::is_none")]
+        include_all: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limit: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        next: Option<String>,
+    }
+
+    impl PagedQuery for ApplicationQuery {
+        fn page(&self, next: Option<&str>, limit: Option<u64>) -> ApplicationQuery {
+            let mut query = self.clone();
+            if next.is_some() {
+                query.next = next.map(str::to_string);
+            }
+            if limit.is_some() {
+                query.limit = limit;
+            }
+            query
+        }
+    }
+
+    impl ApplicationQuery {
+        pub fn new() -> ApplicationQuery {
+            ApplicationQuery::default()
+        }
+
+        pub fn application_id(mut self, application_id: u64) -> ApplicationQuery {
+            self.application_id = Some(application_id);
+            self
+        }
+
+        /// Only applications created by the address
+        pub fn creator(mut self, creator: &Address) -> ApplicationQuery {
+            self.creator = Some(creator.encode_string());
+            self
+        }
+
+        /// Include deleted applications
+        pub fn include_all(mut self, include_all: bool) -> ApplicationQuery {
+            self.include_all = Some(include_all);
+            self
+        }
+
+        /// The maximum number of results in a page
+        pub fn limit(mut self, limit: u64) -> ApplicationQuery {
+            self.limit = Some(limit);
+            self
+        }
+
+        /// The token of the page to get, from the `next_token` of the previous page
+        pub fn next(mut self, next: &str) -> ApplicationQuery {
+            self.next = Some(next.to_string());
+            self
+        }
+    }
+}
+
+pub mod models {
+    use serde::{Deserialize, Serialize};
+
+    pub use crate::algod_v2::models::{
+        ApplicationParams, AssetParams, StateSchema, TealKeyValue, TealValue,
+    };
+    use crate::util::{deserialize_bytes, deserialize_hash, serialize_bytes, serialize_hash};
+    use crate::HashDigest;
+    use crate::MicroAlgos;
+    use crate::Round;
+
+    /// The health of the indexer
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct HealthCheck {
+        #[serde(rename = "db-available")]
+        pub db_available: bool,
+
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub errors: Vec<String>,
+
+        /// Whether the indexer is migrating its database, in which case results may be incomplete
+        #[serde(rename = "is-migrating")]
+        pub is_migrating: bool,
+
+        pub message: String,
+
+        /// The last round the indexer has imported
+        pub round: Round,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub version: Option<String>,
+    }
+
+    /// Account information at a given round
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Account {
+        /// The account public key
+        pub address: String,
+
+        /// The total number of MicroAlgos in the account
+        pub amount: MicroAlgos,
+
+        /// The amount of MicroAlgos in the account, without the pending rewards
+        #[serde(rename = "amount-without-pending-rewards")]
+        pub amount_without_pending_rewards: MicroAlgos,
+
+        /// The local state of the applications the account has opted in to
+        #[serde(
+            rename = "apps-local-state",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub apps_local_state: Vec<ApplicationLocalState>,
+
+        /// The total schema of the local state of the applications the account has opted in to
+        #[serde(
+            rename = "apps-total-schema",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub apps_total_schema: Option<StateSchema>,
+
+        /// The assets held by the account
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub assets: Vec<AssetHolding>,
+
+        /// The address authorized to sign for the account, set when the account has been rekeyed
+        #[serde(rename = "auth-addr", default, skip_serializing_if = "Option::is_none")]
+        pub auth_addr: Option<String>,
+
+        /// The round the account was closed in
+        #[serde(
+            rename = "closed-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub closed_at_round: Option<Round>,
+
+        /// The applications created by the account
+        #[serde(
+            rename = "created-apps",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub created_apps: Vec<Application>,
+
+        /// The assets created by the account
+        #[serde(
+            rename = "created-assets",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub created_assets: Vec<Asset>,
+
+        /// The round the account was first funded in
+        #[serde(
+            rename = "created-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub created_at_round: Option<Round>,
+
+        /// Whether the account is currently closed
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub deleted: Option<bool>,
+
+        /// The amount of MicroAlgos of pending rewards in this account
+        #[serde(rename = "pending-rewards")]
+        pub pending_rewards: MicroAlgos,
+
+        /// The rewards level at which the account last received rewards
+        #[serde(
+            rename = "reward-base",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub reward_base: Option<u64>,
+
+        /// The total rewards of MicroAlgos the account has received
+        pub rewards: MicroAlgos,
+
+        /// The round for which this information is relevant
+        pub round: Round,
+
+        /// The kind of signature used by the account: "sig", "msig" or "lsig"
+        #[serde(rename = "sig-type", default, skip_serializing_if = "Option::is_none")]
+        pub sig_type: Option<String>,
+
+        /// Status indicates the delegation status of the account's MicroAlgos
+        pub status: String,
+    }
+
+    /// An account's holding of an asset
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetHolding {
+        /// The amount of the asset held, in the asset's base units
+        pub amount: u64,
+
+        #[serde(rename = "asset-id")]
+        pub asset_id: u64,
+
+        /// Whether the holding is currently closed out
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub deleted: Option<bool>,
+
+        #[serde(rename = "is-frozen")]
+        pub is_frozen: bool,
+
+        #[serde(
+            rename = "opted-in-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub opted_in_at_round: Option<Round>,
+
+        #[serde(
+            rename = "opted-out-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub opted_out_at_round: Option<Round>,
+    }
+
+    /// An account's holding of an asset, as listed in the balances of the asset
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct MiniAssetHolding {
+        pub address: String,
+
+        /// The amount of the asset held, in the asset's base units
+        pub amount: u64,
+
+        /// Whether the holding is currently closed out
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub deleted: Option<bool>,
+
+        #[serde(rename = "is-frozen")]
+        pub is_frozen: bool,
+
+        #[serde(
+            rename = "opted-in-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub opted_in_at_round: Option<Round>,
+
+        #[serde(
+            rename = "opted-out-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub opted_out_at_round: Option<Round>,
+    }
+
+    /// An asset and its params
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Asset {
+        #[serde(
+            rename = "created-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub created_at_round: Option<Round>,
+
+        /// Whether the asset is currently destroyed
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub deleted: Option<bool>,
+
+        #[serde(
+            rename = "destroyed-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub destroyed_at_round: Option<Round>,
+
+        /// The asset id
+        pub index: u64,
+
+        pub params: AssetParams,
+    }
+
+    /// An application and its params
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Application {
+        #[serde(
+            rename = "created-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub created_at_round: Option<Round>,
+
+        /// Whether the application is currently deleted
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub deleted: Option<bool>,
+
+        #[serde(
+            rename = "deleted-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub deleted_at_round: Option<Round>,
+
+        /// The application id
+        pub id: u64,
+
+        pub params: ApplicationParams,
+    }
+
+    /// An account's local state of an application
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ApplicationLocalState {
+        #[serde(
+            rename = "closed-out-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub closed_out_at_round: Option<Round>,
+
+        /// Whether the account is currently closed out of the application
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub deleted: Option<bool>,
+
+        /// The application id
+        pub id: u64,
+
+        #[serde(rename = "key-value", default, skip_serializing_if = "Vec::is_empty")]
+        pub key_value: Vec<TealKeyValue>,
+
+        #[serde(
+            rename = "opted-in-at-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub opted_in_at_round: Option<Round>,
+
+        pub schema: StateSchema,
+    }
+
+    /// A confirmed transaction
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Transaction {
+        #[serde(
+            rename = "application-transaction",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub application_transaction: Option<ApplicationTransaction>,
+
+        #[serde(
+            rename = "asset-config-transaction",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub asset_config_transaction: Option<AssetConfigTransaction>,
+
+        #[serde(
+            rename = "asset-freeze-transaction",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub asset_freeze_transaction: Option<AssetFreezeTransaction>,
+
+        #[serde(
+            rename = "asset-transfer-transaction",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub asset_transfer_transaction: Option<AssetTransferTransaction>,
+
+        /// The address that authorized the transaction, set when it differs from the sender
+        #[serde(rename = "auth-addr", default, skip_serializing_if = "Option::is_none")]
+        pub auth_addr: Option<String>,
+
+        /// Rewards applied to the close remainder to account
+        #[serde(
+            rename = "close-rewards",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub close_rewards: Option<MicroAlgos>,
+
+        /// The amount sent to the close remainder to account
+        #[serde(
+            rename = "closing-amount",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub closing_amount: Option<MicroAlgos>,
+
+        #[serde(
+            rename = "confirmed-round",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub confirmed_round: Option<Round>,
+
+        /// The id of the application created by the transaction
+        #[serde(
+            rename = "created-application-index",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub created_application_index: Option<u64>,
+
+        /// The id of the asset created by the transaction
+        #[serde(
+            rename = "created-asset-index",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub created_asset_index: Option<u64>,
+
+        pub fee: MicroAlgos,
+
+        #[serde(rename = "first-valid")]
+        pub first_valid: Round,
+
+        /// The base64 encoded genesis hash
+        #[serde(
+            rename = "genesis-hash",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub genesis_hash: Option<String>,
+
+        #[serde(
+            rename = "genesis-id",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub genesis_id: Option<String>,
+
+        /// The base64 encoded group id
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub group: Option<String>,
+
+        /// The transaction id
+        pub id: String,
+
+        /// The position of the transaction in its block
+        #[serde(
+            rename = "intra-round-offset",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub intra_round_offset: Option<u64>,
+
+        #[serde(
+            rename = "keyreg-transaction",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub keyreg_transaction: Option<KeyregTransaction>,
+
+        #[serde(rename = "last-valid")]
+        pub last_valid: Round,
+
+        /// The base64 encoded lease
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub lease: Option<String>,
+
+        #[serde(
+            default,
+            skip_serializing_if = "Vec::is_empty",
+            deserialize_with = "deserialize_bytes",
+            serialize_with = "serialize_bytes"
+        )]
+        pub note: Vec<u8>,
+
+        #[serde(
+            rename = "payment-transaction",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub payment_transaction: Option<PaymentTransaction>,
+
+        /// Rewards applied to the receiver account
+        #[serde(
+            rename = "receiver-rewards",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub receiver_rewards: Option<MicroAlgos>,
+
+        /// The address the sender was rekeyed to by the transaction
+        #[serde(rename = "rekey-to", default, skip_serializing_if = "Option::is_none")]
+        pub rekey_to: Option<String>,
+
+        /// The time of the block the transaction was confirmed in, in seconds since epoch
+        #[serde(
+            rename = "round-time",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub round_time: Option<i64>,
+
+        pub sender: String,
+
+        /// Rewards applied to the sender account
+        #[serde(
+            rename = "sender-rewards",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub sender_rewards: Option<MicroAlgos>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub signature: Option<TransactionSignature>,
+
+        /// The transaction type: "pay", "keyreg", "acfg", "axfer", "afrz" or "appl"
+        #[serde(rename = "tx-type")]
+        pub tx_type: String,
+    }
+
+    /// Fields of a payment transaction
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct PaymentTransaction {
+        pub amount: MicroAlgos,
+
+        /// The amount sent to the close remainder to account
+        #[serde(
+            rename = "close-amount",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub close_amount: Option<MicroAlgos>,
+
+        #[serde(
+            rename = "close-remainder-to",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub close_remainder_to: Option<String>,
+
+        pub receiver: String,
+    }
+
+    /// Fields of a key registration transaction
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct KeyregTransaction {
+        #[serde(
+            rename = "non-participation",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub non_participation: Option<bool>,
+
+        /// The base64 encoded VRF public key
+        #[serde(
+            rename = "selection-participation-key",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub selection_participation_key: Option<String>,
+
+        #[serde(
+            rename = "vote-first-valid",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub vote_first_valid: Option<Round>,
+
+        #[serde(
+            rename = "vote-key-dilution",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub vote_key_dilution: Option<u64>,
+
+        #[serde(
+            rename = "vote-last-valid",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub vote_last_valid: Option<Round>,
+
+        /// The base64 encoded participation public key
+        #[serde(
+            rename = "vote-participation-key",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub vote_participation_key: Option<String>,
+    }
+
+    /// Fields of an asset configuration transaction
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetConfigTransaction {
+        /// The id of the asset, 0 when the transaction creates it
+        #[serde(rename = "asset-id", default, skip_serializing_if = "Option::is_none")]
+        pub asset_id: Option<u64>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub params: Option<AssetParams>,
+    }
+
+    /// Fields of an asset freeze transaction
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetFreezeTransaction {
+        /// The account whose holding is frozen or unfrozen
+        pub address: String,
+
+        #[serde(rename = "asset-id")]
+        pub asset_id: u64,
+
+        #[serde(rename = "new-freeze-status")]
+        pub new_freeze_status: bool,
+    }
+
+    /// Fields of an asset transfer transaction
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetTransferTransaction {
+        /// The amount of the asset transferred, in the asset's base units
+        pub amount: u64,
+
+        #[serde(rename = "asset-id")]
+        pub asset_id: u64,
+
+        /// The amount sent to the close to account
+        #[serde(
+            rename = "close-amount",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub close_amount: Option<u64>,
+
+        #[serde(rename = "close-to", default, skip_serializing_if = "Option::is_none")]
+        pub close_to: Option<String>,
+
+        pub receiver: String,
+
+        /// The account the assets were revoked from, for clawback transactions
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub sender: Option<String>,
+    }
+
+    /// Fields of an application call transaction
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ApplicationTransaction {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub accounts: Vec<String>,
+
+        /// The base64 encoded arguments
+        #[serde(
+            rename = "application-args",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub application_args: Vec<String>,
+
+        /// The id of the application, 0 when the transaction creates it
+        #[serde(rename = "application-id")]
+        pub application_id: u64,
+
+        /// The base64 encoded approval program
+        #[serde(
+            rename = "approval-program",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub approval_program: Option<String>,
+
+        /// The base64 encoded clear state program
+        #[serde(
+            rename = "clear-state-program",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub clear_state_program: Option<String>,
+
+        #[serde(
+            rename = "extra-program-pages",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub extra_program_pages: Option<u64>,
+
+        #[serde(
+            rename = "foreign-apps",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub foreign_apps: Vec<u64>,
+
+        #[serde(
+            rename = "foreign-assets",
+            default,
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        pub foreign_assets: Vec<u64>,
+
+        #[serde(
+            rename = "global-state-schema",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub global_state_schema: Option<StateSchema>,
+
+        #[serde(
+            rename = "local-state-schema",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub local_state_schema: Option<StateSchema>,
+
+        /// The action of the call: "noop", "optin", "closeout", "clear", "update" or "delete"
+        #[serde(rename = "on-completion")]
+        pub on_completion: String,
+    }
+
+    /// The signature authorizing a transaction, only one of the fields is set
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TransactionSignature {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub logicsig: Option<LogicSigSignature>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub multisig: Option<MultisigSignature>,
+
+        /// The base64 encoded signature
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub sig: Option<String>,
+    }
+
+    /// A multisig signature
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct MultisigSignature {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub subsignature: Vec<MultisigSubsignature>,
+
+        pub threshold: u64,
+
+        pub version: u64,
+    }
+
+    /// A subsignature of a multisig signature, the signature is unset when the key didn't sign
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct MultisigSubsignature {
+        /// The base64 encoded public key
+        #[serde(rename = "public-key")]
+        pub public_key: String,
+
+        /// The base64 encoded signature
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub signature: Option<String>,
+    }
+
+    /// A logic signature
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct LogicSigSignature {
+        /// The base64 encoded arguments
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub args: Vec<String>,
+
+        /// The base64 encoded program
+        pub logic: String,
+
+        #[serde(
+            rename = "multisig-signature",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub multisig_signature: Option<MultisigSignature>,
+
+        /// The base64 encoded signature of a delegating account
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub signature: Option<String>,
+    }
+
+    /// A block and its transactions
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct Block {
+        #[serde(
+            rename = "genesis-hash",
+            deserialize_with = "deserialize_hash",
+            serialize_with = "serialize_hash"
+        )]
+        pub genesis_hash: HashDigest,
+
+        #[serde(rename = "genesis-id")]
+        pub genesis_id: String,
+
+        /// The base64 encoded hash of the previous block
+        #[serde(rename = "previous-block-hash")]
+        pub previous_block_hash: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub rewards: Option<BlockRewards>,
+
+        pub round: Round,
+
+        /// The base64 encoded sortition seed
+        pub seed: String,
+
+        /// TimeStamp in seconds since epoch
+        pub timestamp: i64,
+
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub transactions: Vec<Transaction>,
+
+        /// The base64 encoded root of the merkle tree of the block's transaction ids
+        #[serde(rename = "transactions-root")]
+        pub transactions_root: String,
+
+        /// The number of transactions committed before this block
+        #[serde(
+            rename = "txn-counter",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub txn_counter: Option<u64>,
+
+        #[serde(
+            rename = "upgrade-state",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub upgrade_state: Option<BlockUpgradeState>,
+
+        #[serde(
+            rename = "upgrade-vote",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub upgrade_vote: Option<BlockUpgradeVote>,
+    }
+
+    /// The rewards state of a block
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct BlockRewards {
+        #[serde(rename = "fee-sink")]
+        pub fee_sink: String,
+
+        #[serde(rename = "rewards-calculation-round")]
+        pub rewards_calculation_round: Round,
+
+        #[serde(rename = "rewards-level")]
+        pub rewards_level: u64,
+
+        #[serde(rename = "rewards-pool")]
+        pub rewards_pool: String,
+
+        #[serde(rename = "rewards-rate")]
+        pub rewards_rate: u64,
+
+        #[serde(rename = "rewards-residue")]
+        pub rewards_residue: u64,
+    }
+
+    /// The state of a protocol upgrade as of a block
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct BlockUpgradeState {
+        #[serde(rename = "current-protocol")]
+        pub current_protocol: String,
+
+        #[serde(
+            rename = "next-protocol",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_protocol: Option<String>,
+
+        #[serde(
+            rename = "next-protocol-approvals",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_protocol_approvals: Option<u64>,
+
+        #[serde(
+            rename = "next-protocol-switch-on",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_protocol_switch_on: Option<Round>,
+
+        #[serde(
+            rename = "next-protocol-vote-before",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_protocol_vote_before: Option<Round>,
+    }
+
+    /// The vote of the block proposer on protocol upgrades
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct BlockUpgradeVote {
+        #[serde(
+            rename = "upgrade-approve",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub upgrade_approve: Option<bool>,
+
+        #[serde(
+            rename = "upgrade-delay",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub upgrade_delay: Option<u64>,
+
+        #[serde(
+            rename = "upgrade-propose",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub upgrade_propose: Option<String>,
+    }
+
+    /// A page of accounts
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AccountsResponse {
+        pub accounts: Vec<Account>,
+
+        /// The round the results were computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
+
+        /// The token of the next page, unset on the last page
+        #[serde(
+            rename = "next-token",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_token: Option<String>,
+    }
+
+    /// A single account
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AccountResponse {
+        pub account: Account,
+
+        /// The round the result was computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
+    }
+
+    /// A page of transactions
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TransactionsResponse {
+        /// The round the results were computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
+
+        /// The token of the next page, unset on the last page
+        #[serde(
+            rename = "next-token",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_token: Option<String>,
+
+        pub transactions: Vec<Transaction>,
+    }
+
+    /// A single transaction
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TransactionResponse {
+        /// The round the result was computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
+
+        pub transaction: Transaction,
+    }
+
+    /// A page of assets
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetsResponse {
+        pub assets: Vec<Asset>,
+
+        /// The round the results were computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
+
+        /// The token of the next page, unset on the last page
+        #[serde(
+            rename = "next-token",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_token: Option<String>,
+    }
+
+    /// A single asset
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetResponse {
+        pub asset: Asset,
+
+        /// The round the result was computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
+    }
+
+    /// A page of the holders of an asset
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct AssetBalancesResponse {
+        pub balances: Vec<MiniAssetHolding>,
+
+        /// The round the results were computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
+
+        /// The token of the next page, unset on the last page
+        #[serde(
+            rename = "next-token",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_token: Option<String>,
+    }
+
+    /// A page of applications
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ApplicationsResponse {
+        pub applications: Vec<Application>,
+
+        /// The round the results were computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
+
+        /// The token of the next page, unset on the last page
+        #[serde(
+            rename = "next-token",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub next_token: Option<String>,
+    }
+
+    /// A single application
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ApplicationResponse {
+        /// The application, unset if it doesn't exist
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub application: Option<Application>,
+
+        /// The round the result was computed at
+        #[serde(rename = "current-round")]
+        pub current_round: Round,
     }
 }
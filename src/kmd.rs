@@ -1,10 +1,14 @@
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
 use crate::crypto::MultisigSignature;
 use crate::kmd::requests::*;
 use crate::kmd::responses::*;
 use crate::transaction::Transaction;
-use crate::{Ed25519PublicKey, Error, MasterDerivationKey};
+#[cfg(feature = "async")]
+use crate::transport::{AsyncHttpTransport, AsyncReqwestTransport};
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, ReqwestTransport};
+use crate::{Ed25519PublicKey, Error, HttpError, MasterDerivationKey};
 
 const KMD_TOKEN_HEADER: &str = "X-KMD-API-Token";
 
@@ -12,15 +16,23 @@ const KMD_TOKEN_HEADER: &str = "X-KMD-API-Token";
 pub struct KmdClient {
     address: String,
     token: String,
-    http_client: reqwest::Client,
+    transport: Box<dyn HttpTransport>,
 }
 
 impl KmdClient {
     pub fn new(address: &str, token: &str) -> KmdClient {
+        KmdClient::new_with_transport(address, token, ReqwestTransport::new())
+    }
+
+    /// Creates a client sending its requests through the given transport
+    pub fn new_with_transport<T>(address: &str, token: &str, transport: T) -> KmdClient
+    where
+        T: HttpTransport + 'static,
+    {
         KmdClient {
             address: address.to_string(),
             token: token.to_string(),
-            http_client: reqwest::Client::new(),
+            transport: Box::new(transport),
         }
     }
 
@@ -281,25 +293,324 @@ impl KmdClient {
     where
         R: APIV1Request,
     {
-        let response = self
-            .http_client
-            .request(R::METHOD, &format!("{}/{}", self.address, R::PATH))
-            .header(KMD_TOKEN_HEADER, &self.token)
-            .header("Accept", "application/json")
-            .json(&req)
-            .send()?
-            .text()?;
-        if let Ok(envelope) = serde_json::from_str::<APIV1ResponseEnvelope>(&response) {
-            if envelope.error {
-                return Err(Error::Api(envelope.message));
-            }
+        let request = v1_request(&self.address, &self.token, &req)?;
+        let response = self.transport.send(request)?;
+        v1_response(response)
+    }
+}
+
+/// Async version of [KmdClient], available with the `async` feature
+#[cfg(feature = "async")]
+pub struct AsyncKmdClient {
+    address: String,
+    token: String,
+    transport: Box<dyn AsyncHttpTransport>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncKmdClient {
+    pub fn new(address: &str, token: &str) -> AsyncKmdClient {
+        AsyncKmdClient::new_with_transport(address, token, AsyncReqwestTransport::new())
+    }
+
+    /// Creates a client sending its requests through the given transport
+    pub fn new_with_transport<T>(address: &str, token: &str, transport: T) -> AsyncKmdClient
+    where
+        T: AsyncHttpTransport + 'static,
+    {
+        AsyncKmdClient {
+            address: address.to_string(),
+            token: token.to_string(),
+            transport: Box::new(transport),
+        }
+    }
+
+    /// Retrieves the current version
+    pub async fn versions(&self) -> Result<VersionsResponse, Error> {
+        self.do_v1_request(VersionsRequest).await
+    }
+
+    /// List all of the wallets that kmd is aware of
+    pub async fn list_wallets(&self) -> Result<ListWalletsResponse, Error> {
+        self.do_v1_request(ListWalletsRequest).await
+    }
+
+    /// Creates a wallet
+    pub async fn create_wallet(
+        &self,
+        wallet_name: &str,
+        wallet_password: &str,
+        wallet_driver_name: &str,
+        master_derivation_key: MasterDerivationKey,
+    ) -> Result<CreateWalletResponse, Error> {
+        let req = CreateWalletRequest {
+            master_derivation_key,
+            wallet_driver_name: wallet_driver_name.to_string(),
+            wallet_name: wallet_name.to_string(),
+            wallet_password: wallet_password.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Unlock the wallet and return a wallet token that can be used for subsequent operations
+    ///
+    /// These tokens expire periodically and must be renewed.
+    /// You can see how much time remains until expiration with [get_wallet](KmdClient::get_wallet) and renew it with [renew_wallet_handle](KmdClient::renew_wallet_handle).
+    /// When you're done, you can invalidate the token with [release_wallet_handle](KmdClient::release_wallet_handle)
+    pub async fn init_wallet_handle(
+        &self,
+        wallet_id: &str,
+        wallet_password: &str,
+    ) -> Result<InitWalletHandleResponse, Error> {
+        let req = InitWalletHandleRequest {
+            wallet_id: wallet_id.to_string(),
+            wallet_password: wallet_password.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Release a wallet handle token
+    pub async fn release_wallet_handle(
+        &self,
+        wallet_handle: &str,
+    ) -> Result<ReleaseWalletHandleResponse, Error> {
+        let req = ReleaseWalletHandleRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Renew a wallet handle token
+    pub async fn renew_wallet_handle(
+        &self,
+        wallet_handle: &str,
+    ) -> Result<RenewWalletHandleResponse, Error> {
+        let req = RenewWalletHandleRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Rename a wallet
+    pub async fn rename_wallet(
+        &self,
+        wallet_id: &str,
+        wallet_password: &str,
+        new_name: &str,
+    ) -> Result<RenameWalletResponse, Error> {
+        let req = RenameWalletRequest {
+            wallet_id: wallet_id.to_string(),
+            wallet_password: wallet_password.to_string(),
+            wallet_name: new_name.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Get wallet info
+    pub async fn get_wallet(&self, wallet_handle: &str) -> Result<GetWalletResponse, Error> {
+        let req = GetWalletRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Export the master derivation key from a wallet
+    pub async fn export_master_derivation_key(
+        &self,
+        wallet_handle: &str,
+        wallet_password: &str,
+    ) -> Result<ExportMasterDerivationKeyResponse, Error> {
+        let req = ExportMasterDerivationKeyRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            wallet_password: wallet_password.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Import an externally generated key into the wallet
+    pub async fn import_key(
+        &self,
+        wallet_handle: &str,
+        private_key: [u8; 32],
+    ) -> Result<ImportKeyResponse, Error> {
+        let req = ImportKeyRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            private_key,
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Export the Ed25519 seed associated with the passed address
+    ///
+    /// Note the first 32 bytes of the returned value is the seed, the second 32 bytes is the public key
+    pub async fn export_key(
+        &self,
+        wallet_handle: &str,
+        wallet_password: &str,
+        address: &str,
+    ) -> Result<ExportKeyResponse, Error> {
+        let req = ExportKeyRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            address: address.to_string(),
+            wallet_password: wallet_password.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Generates a key and adds it to the wallet, returning the public key
+    pub async fn generate_key(&self, wallet_handle: &str) -> Result<GenerateKeyResponse, Error> {
+        let req = GenerateKeyRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            display_mnemonic: false,
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Deletes the key from the wallet
+    pub async fn delete_key(
+        &self,
+        wallet_handle: &str,
+        wallet_password: &str,
+        address: &str,
+    ) -> Result<DeleteKeyResponse, Error> {
+        let req = DeleteKeyRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            wallet_password: wallet_password.to_string(),
+            address: address.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// List all of the public keys in the wallet
+    pub async fn list_keys(&self, wallet_handle: &str) -> Result<ListKeysResponse, Error> {
+        let req = ListKeysRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Sign a transaction
+    pub async fn sign_transaction(
+        &self,
+        wallet_handle: &str,
+        wallet_password: &str,
+        transaction: &Transaction,
+    ) -> Result<SignTransactionResponse, Error> {
+        let transaction_bytes = rmp_serde::to_vec_named(transaction)?;
+        let req = SignTransactionRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            transaction: transaction_bytes,
+            wallet_password: wallet_password.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Lists all of the multisig accounts whose preimages this wallet stores
+    pub async fn list_multisig(&self, wallet_handle: &str) -> Result<ListMultisigResponse, Error> {
+        let req = ListMultisigRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Import a multisig account
+    pub async fn import_multisig(
+        &self,
+        wallet_handle: &str,
+        version: u8,
+        threshold: u8,
+        pks: &[Ed25519PublicKey],
+    ) -> Result<ImportMultisigResponse, Error> {
+        let req = ImportMultisigRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            multisig_version: version,
+            threshold,
+            pks: pks.to_vec(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Export multisig address metadata
+    pub async fn export_multisig(
+        &self,
+        wallet_handle: &str,
+        address: &str,
+    ) -> Result<ExportMultisigResponse, Error> {
+        let req = ExportMultisigRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            address: address.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Delete a multisig from the wallet
+    pub async fn delete_multisig(
+        &self,
+        wallet_handle: &str,
+        wallet_password: &str,
+        address: &str,
+    ) -> Result<DeleteMultisigResponse, Error> {
+        let req = DeleteMultisigRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            wallet_password: wallet_password.to_string(),
+            address: address.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    /// Start a multisig signature or add a signature to a partially completed multisig signature
+    pub async fn sign_multisig_transaction(
+        &self,
+        wallet_handle: &str,
+        wallet_password: &str,
+        transaction: &Transaction,
+        public_key: Ed25519PublicKey,
+        partial_multisig: Option<MultisigSignature>,
+    ) -> Result<SignMultisigTransactionResponse, Error> {
+        let transaction_bytes = rmp_serde::to_vec_named(transaction)?;
+        let req = SignMultisigTransactionRequest {
+            wallet_handle_token: wallet_handle.to_string(),
+            transaction: transaction_bytes,
+            public_key,
+            partial_multisig,
+            wallet_password: wallet_password.to_string(),
+        };
+        self.do_v1_request(req).await
+    }
+
+    async fn do_v1_request<R>(&self, req: R) -> Result<R::Response, Error>
+    where
+        R: APIV1Request,
+    {
+        let request = v1_request(&self.address, &self.token, &req)?;
+        let response = self.transport.send(request).await?;
+        v1_response(response)
+    }
+}
+
+fn v1_request<R: APIV1Request>(address: &str, token: &str, req: &R) -> Result<HttpRequest, Error> {
+    HttpRequest::new(R::METHOD, format!("{}/{}", address, R::PATH))
+        .header(KMD_TOKEN_HEADER, token)
+        .header("Accept", "application/json")
+        .json(req)
+}
+
+// Errors are reported in an envelope, which may come with a successful status code
+fn v1_response<T: DeserializeOwned>(response: HttpResponse) -> Result<T, Error> {
+    if let Ok(envelope) = serde_json::from_slice::<APIV1ResponseEnvelope>(&response.body) {
+        if envelope.error {
+            return Err(Error::Http(HttpError {
+                status: response.status,
+                message: envelope.message,
+                data: None,
+            }));
         }
-        Ok(serde_json::from_str(&response)?)
     }
+    response.json()
 }
 
 pub mod requests {
-    use reqwest::Method;
+    use crate::transport::Method;
     use serde::de::DeserializeOwned;
     use serde::Serialize;
 
@@ -460,121 +771,121 @@ pub mod requests {
     impl APIV1Request for VersionsRequest {
         type Response = VersionsResponse;
         const PATH: &'static str = "versions";
-        const METHOD: Method = Method::GET;
+        const METHOD: Method = Method::Get;
     }
 
     impl APIV1Request for ListWalletsRequest {
         type Response = ListWalletsResponse;
         const PATH: &'static str = "v1/wallets";
-        const METHOD: Method = Method::GET;
+        const METHOD: Method = Method::Get;
     }
 
     impl APIV1Request for CreateWalletRequest {
         type Response = CreateWalletResponse;
         const PATH: &'static str = "v1/wallet";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for InitWalletHandleRequest {
         type Response = InitWalletHandleResponse;
         const PATH: &'static str = "v1/wallet/init";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for ReleaseWalletHandleRequest {
         type Response = ReleaseWalletHandleResponse;
         const PATH: &'static str = "v1/wallet/release";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for RenewWalletHandleRequest {
         type Response = RenewWalletHandleResponse;
         const PATH: &'static str = "v1/wallet/renew";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for RenameWalletRequest {
         type Response = RenameWalletResponse;
         const PATH: &'static str = "v1/wallet/rename";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for GetWalletRequest {
         type Response = GetWalletResponse;
         const PATH: &'static str = "v1/wallet/info";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for ExportMasterDerivationKeyRequest {
         type Response = ExportMasterDerivationKeyResponse;
         const PATH: &'static str = "v1/master-key/export";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for ImportKeyRequest {
         type Response = ImportKeyResponse;
         const PATH: &'static str = "v1/key/import";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for ExportKeyRequest {
         type Response = ExportKeyResponse;
         const PATH: &'static str = "v1/key/export";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for GenerateKeyRequest {
         type Response = GenerateKeyResponse;
         const PATH: &'static str = "v1/key";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for DeleteKeyRequest {
         type Response = DeleteKeyResponse;
         const PATH: &'static str = "v1/key";
-        const METHOD: Method = Method::DELETE;
+        const METHOD: Method = Method::Delete;
     }
 
     impl APIV1Request for ListKeysRequest {
         type Response = ListKeysResponse;
         const PATH: &'static str = "v1/key/list";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for SignTransactionRequest {
         type Response = SignTransactionResponse;
         const PATH: &'static str = "v1/transaction/sign";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for ListMultisigRequest {
         type Response = ListMultisigResponse;
         const PATH: &'static str = "v1/multisig/list";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for ImportMultisigRequest {
         type Response = ImportMultisigResponse;
         const PATH: &'static str = "v1/multisig/import";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for ExportMultisigRequest {
         type Response = ExportMultisigResponse;
         const PATH: &'static str = "v1/multisig/export";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 
     impl APIV1Request for DeleteMultisigRequest {
         type Response = DeleteMultisigResponse;
         const PATH: &'static str = "v1/multisig";
-        const METHOD: Method = Method::DELETE;
+        const METHOD: Method = Method::Delete;
     }
 
     impl APIV1Request for SignMultisigTransactionRequest {
         type Response = SignMultisigTransactionResponse;
         const PATH: &'static str = "v1/multisig/sign";
-        const METHOD: Method = Method::POST;
+        const METHOD: Method = Method::Post;
     }
 }
 
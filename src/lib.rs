@@ -8,9 +8,17 @@ pub mod auction;
 pub mod crypto;
 /// Key management daemon
 pub mod kmd;
+/// Off-chain messages signed with the "MX" prefix, which no transaction or bid can collide with
+pub mod message;
 /// Support for turning 32 byte keys into human-readable mnemonics and back
 pub mod mnemonic;
+/// Collecting the signatures of multisig transactions from their co-signers over time
+pub mod multisig;
+/// Signers that abstract over local keys, kmd wallets, multisig and logic signatures
+pub mod signer;
 pub mod transaction;
+/// HTTP transport used by the clients
+pub mod transport;
 pub(crate) mod util;
 pub mod indexer;
 
@@ -21,6 +29,14 @@ pub use indexer::IndexerClient;
 pub use crypto::Address;
 pub use kmd::KmdClient;
 pub use algod_v2::AlgodClientV2;
+#[cfg(feature = "async")]
+pub use algod::AsyncAlgodClient;
+#[cfg(feature = "async")]
+pub use algod_v2::AsyncAlgodClientV2;
+#[cfg(feature = "async")]
+pub use indexer::AsyncIndexerClient;
+#[cfg(feature = "async")]
+pub use kmd::AsyncKmdClient;
 
 /// MicroAlgos are the base unit of currency in Algorand
 #[derive(Copy, Clone, Default, Debug, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
@@ -41,6 +57,26 @@ pub struct VRFPK(pub [u8; 32]);
 pub struct Ed25519PublicKey(pub [u8; 32]);
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct MasterDerivationKey(pub [u8; 32]);
+/// A 32 byte lease, at most one transaction with a given sender and lease can be confirmed until
+/// the last valid round of the transaction holding the lease. An all zero lease is the same as no
+/// lease: it is left out of the encoding and decodes back as no lease.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Lease(pub [u8; 32]);
+
+impl Lease {
+    /// Create a lease from a byte slice, which must be exactly 32 bytes long
+    pub fn new(bytes: &[u8]) -> Result<Lease, Error> {
+        if bytes.len() != 32 {
+            return Err(Error::Decoding(DecodingError::Length {
+                expected: 32,
+                actual: bytes.len(),
+            }));
+        }
+        let mut lease = [0; 32];
+        lease.copy_from_slice(bytes);
+        Ok(Lease(lease))
+    }
+}
 
 impl MicroAlgos {
     pub fn to_algos(self) -> f64 {
@@ -57,5 +93,93 @@ pub enum Error {
     Reqwest(reqwest::Error),
     Encode(rmp_serde::encode::Error),
     Json(serde_json::Error),
-    Api(String),
+    /// The node answered with an error status
+    Http(HttpError),
+    /// An address, mnemonic or other encoded value couldn't be decoded
+    Decoding(DecodingError),
+    /// The arguments or the transaction to sign are invalid
+    Validation(String),
+    /// A signature is invalid or doesn't authorize what it signs
+    Verification(String),
+    /// The node evicted the transaction from its pool while waiting for it to be confirmed
+    TransactionRejected {
+        transaction_id: String,
+        pool_error: String,
+    },
+    /// The transaction wasn't confirmed within the given number of rounds
+    ConfirmationTimeout { transaction_id: String, rounds: u64 },
+}
+
+impl Error {
+    /// The HTTP status of the response when the node answered with an error
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::Http(err) => Some(err.status),
+            _ => None,
+        }
+    }
+}
+
+/// An error answered by algod, the indexer or kmd
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpError {
+    pub status: u16,
+    /// The message of the error, or the whole body when the node didn't answer with json
+    pub message: String,
+    /// Additional details some endpoints attach to the error
+    pub data: Option<serde_json::Value>,
+}
+
+impl HttpError {
+    /// Parses the `{"message": ..., "data": ...}` body the nodes answer errors with
+    pub fn from_response(status: u16, body: &[u8]) -> HttpError {
+        #[derive(Deserialize)]
+        struct ErrorBody {
+            message: String,
+            data: Option<serde_json::Value>,
+        }
+        match serde_json::from_slice::<ErrorBody>(body) {
+            Ok(body) => HttpError {
+                status,
+                message: body.message,
+                data: body.data,
+            },
+            Err(_) => HttpError {
+                status,
+                message: String::from_utf8_lossy(body).trim().to_string(),
+                data: None,
+            },
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status == 404
+    }
+
+    /// Whether a transaction was rejected because its sender can't afford it
+    pub fn is_overspend(&self) -> bool {
+        self.message.contains("overspend")
+    }
+
+    /// Whether a transaction was rejected because the current round is past its last valid round
+    pub fn is_txn_dead(&self) -> bool {
+        self.message.contains("txn dead")
+    }
+}
+
+/// The ways decoding an address, mnemonic, key or msgpack value can fail
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodingError {
+    /// The string isn't valid base32 or base64
+    Encoding(String),
+    /// The decoded value has the wrong length
+    Length { expected: usize, actual: usize },
+    /// The checksum of an address or mnemonic doesn't match its content
+    Checksum,
+    /// A mnemonic contains a word that isn't in the word list
+    UnknownWord(String),
+    /// A multisig descriptor isn't formatted as `msig:<version>:<threshold>:<addresses>`
+    Multisig(String),
+    /// The bytes aren't the msgpack encoding of the expected value
+    Msgpack(String),
 }
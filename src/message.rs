@@ -0,0 +1,72 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::crypto::{Address, Signature};
+use crate::Error;
+
+/// A typed payload signed off-chain for an application, such as a login challenge or an
+/// attestation, in the style of ARC-60
+///
+/// The domain of the application is signed along with the payload, so a signature made for one
+/// application can't be replayed against another.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Message<T> {
+    /// Identifies the application asking for the signature, e.g. its host name
+    pub domain: String,
+    pub payload: T,
+}
+
+impl<T: Serialize> Message<T> {
+    pub fn new(domain: &str, payload: T) -> Message<T> {
+        Message {
+            domain: domain.to_string(),
+            payload,
+        }
+    }
+
+    /// Returns the canonical json encoding of the domain and payload, with the keys of every
+    /// object sorted, which is signed with the "MX" prefix
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let message = serde_json::json!({
+            "domain": self.domain,
+            "payload": serde_json::to_value(&self.payload)?,
+        });
+        Ok(serde_json::to_vec(&sort_keys(message))?)
+    }
+}
+
+// Rebuilds the objects with their keys in order, whether or not serde_json preserves the order
+// the fields were serialized in
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+/// A message signed by an account
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignedMessage<T> {
+    pub message: Message<T>,
+    /// The account that signed the message
+    pub signer: Address,
+    pub sig: Signature,
+}
+
+impl<T: Serialize> SignedMessage<T> {
+    /// Verifies that the message was signed by the signer
+    pub fn verify(&self) -> Result<(), Error> {
+        self.signer
+            .verify_bytes(&self.message.to_bytes()?, &self.sig)
+    }
+}
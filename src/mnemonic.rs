@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{DecodingError, Error};
 use sha2::Digest;
 use static_assertions::const_assert_eq;
 
@@ -19,10 +19,10 @@ const_assert_eq!(mnemonic_constants; MNEM_LEN_WORDS * BITS_PER_WORD - (CHECKSUM_
 /// of data, and the last 11 bits are reserved for the checksum.
 pub fn from_key(key: &[u8]) -> Result<String, Error> {
     if key.len() != KEY_LEN_BYTES {
-        return Err(Error::Api(format!(
-            "key length must be {} bytes",
-            KEY_LEN_BYTES
-        )));
+        return Err(Error::Decoding(DecodingError::Length {
+            expected: KEY_LEN_BYTES,
+            actual: key.len(),
+        }));
     }
     let check_word = checksum(key);
     let mut words: Vec<_> = to_u11_array(key).into_iter().map(get_word).collect();
@@ -37,32 +37,29 @@ pub fn from_key(key: &[u8]) -> Result<String, Error> {
 pub fn to_key(string: &str) -> Result<[u8; KEY_LEN_BYTES], Error> {
     let mut mnemonic: Vec<&str> = string.split(MNEMONIC_DELIM).collect();
     if mnemonic.len() != MNEM_LEN_WORDS {
-        return Err(Error::Api(format!(
-            "mnemonic {:?} needed {} words, had {}",
-            mnemonic,
-            MNEM_LEN_WORDS,
-            mnemonic.len()
-        )));
+        return Err(Error::Decoding(DecodingError::Length {
+            expected: MNEM_LEN_WORDS,
+            actual: mnemonic.len(),
+        }));
     }
     let check_word = mnemonic.pop().unwrap();
     let mut nums = Vec::with_capacity(mnemonic.len());
     for word in mnemonic {
-        let n = wordlist::WORDLIST.get_full(word).ok_or_else(|| {
-            Error::Api("mnemonic contains word that is not in word list".to_string())
-        })?;
+        let n = wordlist::WORDLIST
+            .get_full(word)
+            .ok_or_else(|| DecodingError::UnknownWord(word.to_string()))?;
         nums.push(n.0 as u32);
     }
     let mut bytes = to_byte_array(&nums);
     if bytes.len() != KEY_LEN_BYTES + 1 {
-        return Err(Error::Api(format!(
-            "wrong key length {}, should be {}",
-            bytes.len(),
-            KEY_LEN_BYTES + 1
-        )));
+        return Err(Error::Decoding(DecodingError::Length {
+            expected: KEY_LEN_BYTES + 1,
+            actual: bytes.len(),
+        }));
     }
     let _ = bytes.pop();
     if check_word != checksum(&bytes) {
-        return Err(Error::Api("checksum failed to validate".to_string()));
+        return Err(Error::Decoding(DecodingError::Checksum));
     }
     let mut key = [0; KEY_LEN_BYTES];
     key.copy_from_slice(&bytes);
@@ -79,7 +76,7 @@ fn checksum(data: &[u8]) -> &str {
 fn to_u11_array(bytes: &[u8]) -> Vec<u32> {
     let mut buf = 0u32;
     let mut bit_count = 0;
-    let mut out = Vec::with_capacity((bytes.len() * 8 + BITS_PER_WORD - 1) / BITS_PER_WORD);
+    let mut out = Vec::with_capacity((bytes.len() * 8).div_ceil(BITS_PER_WORD));
     for &b in bytes {
         buf |= (u32::from(b)) << bit_count;
         bit_count += 8;
@@ -99,7 +96,7 @@ fn to_u11_array(bytes: &[u8]) -> Vec<u32> {
 fn to_byte_array(nums: &[u32]) -> Vec<u8> {
     let mut buf = 0;
     let mut bit_count = 0;
-    let mut out = Vec::with_capacity((nums.len() * BITS_PER_WORD + 8 - 1) / 8);
+    let mut out = Vec::with_capacity((nums.len() * BITS_PER_WORD).div_ceil(8));
     for &n in nums {
         buf |= n << bit_count;
         bit_count += BITS_PER_WORD as u32;
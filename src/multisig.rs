@@ -0,0 +1,156 @@
+use crate::account::Account;
+use crate::crypto::{Address, MultisigAddress, MultisigSignature, MultisigSubsig};
+use crate::transaction::{SignedTransaction, Transaction};
+use crate::Error;
+
+/// A transaction of a multisig address whose signatures are collected from the co-signers over
+/// time, in the spirit of bitcoin's partially signed transactions
+///
+/// Its encoding is the one of a signed transaction, so it can be passed around in files between
+/// co-signers, who each add their signature and merge the contributions of the others. It can
+/// only be finalized once enough of them have signed to meet the threshold.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartiallySignedTransaction {
+    // Always signed by a multisig and nothing else, which multisig() relies on
+    signed_transaction: SignedTransaction,
+}
+
+impl PartiallySignedTransaction {
+    /// Starts collecting the signatures of the multisig address, which must be the sender or the
+    /// address the sender has been rekeyed to
+    ///
+    /// The rekey is assumed and not checked, only the ledger knows the auth address of the sender.
+    /// When the multisig address isn't the sender, it's set as the auth address of the transaction.
+    pub fn new(
+        multisig: &MultisigAddress,
+        transaction: Transaction,
+    ) -> Result<PartiallySignedTransaction, Error> {
+        let subsigs = multisig
+            .public_keys
+            .iter()
+            .map(|key| MultisigSubsig {
+                key: *key,
+                sig: None,
+            })
+            .collect();
+        Ok(PartiallySignedTransaction {
+            signed_transaction: SignedTransaction {
+                logic_sig: None,
+                multisig: Some(MultisigSignature {
+                    version: multisig.version,
+                    threshold: multisig.threshold,
+                    subsigs,
+                }),
+                auth_address: transaction.auth_address(multisig.address()),
+                sig: None,
+                transaction_id: transaction.id()?,
+                transaction,
+            },
+        })
+    }
+
+    /// Continues collecting the signatures of a multisig transaction, checking the signatures it
+    /// already holds
+    pub fn from_signed_transaction(
+        signed_transaction: SignedTransaction,
+    ) -> Result<PartiallySignedTransaction, Error> {
+        let multisig = match &signed_transaction {
+            SignedTransaction {
+                multisig: Some(multisig),
+                sig: None,
+                logic_sig: None,
+                ..
+            } => multisig,
+            _ => {
+                return Err(Error::Validation(
+                    "Only transactions signed by a multisig address can be partially signed"
+                        .to_string(),
+                ))
+            }
+        };
+        let message = signed_transaction.transaction.bytes_to_sign()?;
+        for subsig in &multisig.subsigs {
+            if let Some(sig) = &subsig.sig {
+                if !subsig.key.verify(&message, sig) {
+                    return Err(Error::Verification(format!(
+                        "Multisig signature of {} is invalid",
+                        Address(subsig.key.0)
+                    )));
+                }
+            }
+        }
+        Ok(PartiallySignedTransaction { signed_transaction })
+    }
+
+    /// Decodes a partially signed transaction, such as one written by a co-signer or by `goal`
+    pub fn from_bytes(bytes: &[u8]) -> Result<PartiallySignedTransaction, Error> {
+        Self::from_signed_transaction(SignedTransaction::from_bytes(bytes)?)
+    }
+
+    /// Encodes the partially signed transaction to be passed on to the other co-signers
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.signed_transaction.to_bytes()
+    }
+
+    pub fn transaction(&self) -> &Transaction {
+        &self.signed_transaction.transaction
+    }
+
+    /// The multisig address whose signatures are being collected
+    pub fn multisig_address(&self) -> MultisigAddress {
+        self.multisig().multisig_address()
+    }
+
+    /// Adds the signature of an account of the multisig address
+    pub fn sign(&mut self, account: &Account) -> Result<(), Error> {
+        let signed = account
+            .append_multisig_transaction(self.multisig_address(), &self.signed_transaction)?;
+        self.signed_transaction = signed;
+        Ok(())
+    }
+
+    /// Adds the signatures collected by another co-signer of the same transaction
+    pub fn merge(&mut self, other: &PartiallySignedTransaction) -> Result<(), Error> {
+        self.signed_transaction = Account::merge_multisig_transactions(&[
+            &self.signed_transaction,
+            &other.signed_transaction,
+        ])?;
+        Ok(())
+    }
+
+    /// The addresses of the accounts that have signed, in the order of the multisig address
+    pub fn signers(&self) -> Vec<Address> {
+        self.subsig_addresses(true)
+    }
+
+    /// The addresses of the accounts that haven't signed yet, any of which can sign until the
+    /// threshold is met
+    pub fn remaining_signers(&self) -> Vec<Address> {
+        self.subsig_addresses(false)
+    }
+
+    /// Whether enough accounts have signed to finalize the transaction
+    pub fn is_complete(&self) -> bool {
+        self.signers().len() >= self.multisig().threshold as usize
+    }
+
+    /// Returns the signed transaction ready to be sent, refusing to when the threshold isn't met
+    pub fn finalize(self) -> Result<SignedTransaction, Error> {
+        self.signed_transaction.verify()?;
+        Ok(self.signed_transaction)
+    }
+
+    fn multisig(&self) -> &MultisigSignature {
+        // Checked when the partially signed transaction was created
+        self.signed_transaction.multisig.as_ref().unwrap()
+    }
+
+    fn subsig_addresses(&self, signed: bool) -> Vec<Address> {
+        self.multisig()
+            .subsigs
+            .iter()
+            .filter(|subsig| subsig.sig.is_some() == signed)
+            .map(|subsig| Address(subsig.key.0))
+            .collect()
+    }
+}
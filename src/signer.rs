@@ -0,0 +1,128 @@
+use crate::account::Account;
+use crate::crypto::{LogicSig, MultisigAddress};
+use crate::kmd::KmdClient;
+use crate::transaction::{SignedTransaction, Transaction};
+use crate::Error;
+
+/// Signs some of the transactions of a group, so that code building and sending groups doesn't
+/// depend on where the keys are kept
+pub trait TransactionSigner {
+    /// Signs the transactions at `indexes` in the group, returning them in the order of `indexes`
+    fn sign_group(
+        &self,
+        transactions: &[Transaction],
+        indexes: &[usize],
+    ) -> Result<Vec<SignedTransaction>, Error>;
+}
+
+impl TransactionSigner for Account {
+    fn sign_group(
+        &self,
+        transactions: &[Transaction],
+        indexes: &[usize],
+    ) -> Result<Vec<SignedTransaction>, Error> {
+        sign_each(transactions, indexes, |transaction| {
+            self.sign_transaction(transaction)
+        })
+    }
+}
+
+impl TransactionSigner for LogicSig {
+    fn sign_group(
+        &self,
+        transactions: &[Transaction],
+        indexes: &[usize],
+    ) -> Result<Vec<SignedTransaction>, Error> {
+        sign_each(transactions, indexes, |transaction| {
+            self.sign_transaction(transaction)
+        })
+    }
+}
+
+/// Signs with a key held by a kmd wallet, the wallet handle must have been initialized
+pub struct KmdSigner<'a> {
+    client: &'a KmdClient,
+    wallet_handle: String,
+    wallet_password: String,
+}
+
+impl<'a> KmdSigner<'a> {
+    pub fn new(client: &'a KmdClient, wallet_handle: &str, wallet_password: &str) -> Self {
+        KmdSigner {
+            client,
+            wallet_handle: wallet_handle.to_string(),
+            wallet_password: wallet_password.to_string(),
+        }
+    }
+}
+
+impl TransactionSigner for KmdSigner<'_> {
+    fn sign_group(
+        &self,
+        transactions: &[Transaction],
+        indexes: &[usize],
+    ) -> Result<Vec<SignedTransaction>, Error> {
+        sign_each(transactions, indexes, |transaction| {
+            let response = self.client.sign_transaction(
+                &self.wallet_handle,
+                &self.wallet_password,
+                transaction,
+            )?;
+            SignedTransaction::from_bytes(&response.signed_transaction)
+        })
+    }
+}
+
+/// Collects the signatures of several accounts of a multisig address, which need not reach its
+/// threshold
+pub struct MultisigSigner<'a> {
+    address: MultisigAddress,
+    accounts: Vec<&'a Account>,
+}
+
+impl<'a> MultisigSigner<'a> {
+    pub fn new(address: MultisigAddress, accounts: Vec<&'a Account>) -> Self {
+        MultisigSigner { address, accounts }
+    }
+}
+
+impl TransactionSigner for MultisigSigner<'_> {
+    fn sign_group(
+        &self,
+        transactions: &[Transaction],
+        indexes: &[usize],
+    ) -> Result<Vec<SignedTransaction>, Error> {
+        let (first, others) = self.accounts.split_first().ok_or_else(|| {
+            Error::Validation("Multisig signer must have at least one account".to_string())
+        })?;
+        sign_each(transactions, indexes, |transaction| {
+            let mut signed = first.sign_multisig_transaction(self.address.clone(), transaction)?;
+            for account in others {
+                signed = account.append_multisig_transaction(self.address.clone(), &signed)?;
+            }
+            Ok(signed)
+        })
+    }
+}
+
+// Signs the transactions at the indexes one by one, failing on indexes outside of the group
+fn sign_each<F>(
+    transactions: &[Transaction],
+    indexes: &[usize],
+    sign: F,
+) -> Result<Vec<SignedTransaction>, Error>
+where
+    F: Fn(&Transaction) -> Result<SignedTransaction, Error>,
+{
+    indexes
+        .iter()
+        .map(|&index| match transactions.get(index) {
+            Some(transaction) => sign(transaction),
+            None => Err(Error::Validation(format!(
+                "Transaction {} is not in the group of {} transactions",
+                index,
+                transactions.len()
+            ))),
+        })
+        .collect()
+}
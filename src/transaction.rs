@@ -1,10 +1,20 @@
+use data_encoding::BASE32_NOPAD;
 use serde::{Deserialize, Serialize};
+use sha2::Digest;
 
-use crate::account::Account;
-use crate::crypto::{Address, MultisigSignature, Signature};
-use crate::{Error, HashDigest, MicroAlgos, Round, VotePK, VRFPK};
+use crate::crypto::{
+    Address, LogicSig, MultisigAddress, MultisigSignature, MultisigSubsig, Signature,
+};
+use crate::{Error, HashDigest, Lease, MicroAlgos, Round, VotePK, VRFPK};
+
+/// Builders for each type of transaction, driven by the params suggested by algod
+pub mod builder;
 
 const MIN_TXN_FEE: MicroAlgos = MicroAlgos(1000);
+/// The maximum number of transactions in an atomic group
+pub const MAX_TX_GROUP_SIZE: usize = 16;
+
+type ChecksumAlg = sha2::Sha512Trunc256;
 
 /// Fields always used when creating a transaction, used as an argument in creating a Transaction
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -15,25 +25,56 @@ pub struct BaseTransaction {
     pub note: Vec<u8>,
     pub genesis_id: String,
     pub genesis_hash: HashDigest,
+    pub lease: Option<Lease>,
+}
+
+/// How a transaction will be signed, which determines the size of the signed transaction and so
+/// its fee
+#[derive(Debug, Clone)]
+pub enum SignerKind {
+    /// A signature by a single account, the address the sender has been rekeyed to if it isn't
+    /// the sender itself
+    Single(Option<Address>),
+    /// Signatures by as many keys of the multisig address as its threshold
+    Multisig(MultisigAddress),
+    /// A logic signature, along with the signatures delegating it if there are any
+    LogicSig(LogicSig),
+}
+
+impl Default for SignerKind {
+    fn default() -> Self {
+        SignerKind::Single(None)
+    }
 }
 
 /// A transaction that can appear in a block
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
 pub struct Transaction {
-    #[serde(rename = "sender")]
+    #[serde(rename = "snd")]
     pub sender: Address,
-    #[serde(rename = "fee")]
+    #[serde(rename = "fee", default)]
     pub fee: MicroAlgos,
-    #[serde(rename = "first-valid")]
+    #[serde(rename = "fv", default)]
     pub first_valid: Round,
-    #[serde(rename = "last-valid")]
+    #[serde(rename = "lv", default)]
     pub last_valid: Round,
     #[serde(with = "serde_bytes", default)]
     pub note: Vec<u8>,
-    #[serde(rename = "genesisID", default)]
+    #[serde(rename = "gen", default)]
     pub genesis_id: String,
-    #[serde(rename = "genesishashb64")]
+    #[serde(rename = "gh")]
     pub genesis_hash: HashDigest,
+    /// The group id, set on every transaction of an atomic group with [assign_group_id]
+    #[serde(rename = "grp", default)]
+    pub group: Option<HashDigest>,
+    /// If set, the sender's account is rekeyed so that transactions from it must afterwards be
+    /// authorized by this address
+    #[serde(rename = "rekey", default)]
+    pub rekey_to: Option<Address>,
+    /// Enforces mutual exclusion of transactions with the same sender and lease until the last
+    /// valid round. An all zero lease is left out of the encoding, so it decodes back as `None`.
+    #[serde(rename = "lx", default)]
+    pub lease: Option<Lease>,
     #[serde(flatten)]
     pub txn_type: TransactionType,
 }
@@ -46,6 +87,14 @@ pub enum TransactionType {
     Payment(Payment),
     #[serde(rename = "keyreg")]
     KeyRegistration(KeyRegistration),
+    #[serde(rename = "axfer")]
+    AssetTransfer(AssetTransfer),
+    #[serde(rename = "acfg")]
+    AssetConfig(AssetConfig),
+    #[serde(rename = "afrz")]
+    AssetFreeze(AssetFreeze),
+    #[serde(rename = "appl")]
+    ApplicationCall(ApplicationCall),
 }
 
 /// Fields for a payment transaction
@@ -64,18 +113,185 @@ pub struct Payment {
 /// Fields for a key registration transaction
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
 pub struct KeyRegistration {
-    #[serde(rename = "vote-pk")]
+    #[serde(rename = "votekey")]
     pub vote_pk: VotePK,
-    #[serde(rename = "selection-pk")]
+    #[serde(rename = "selkey")]
     pub selection_pk: VRFPK,
-    #[serde(rename = "vote-first")]
+    #[serde(rename = "votefst", default)]
     pub vote_first: Round,
-    #[serde(rename = "vote-last")]
+    #[serde(rename = "votelst", default)]
     pub vote_last: Round,
-    #[serde(rename = "vote-key-dilution")]
+    #[serde(rename = "votekd", default)]
     pub vote_key_dilution: u64,
 }
 
+/// Fields for an asset transfer transaction
+///
+/// Also used to opt in to an asset (a zero amount transfer to yourself), to revoke assets
+/// as the clawback account, and to close out an asset holding.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+pub struct AssetTransfer {
+    #[serde(rename = "xaid", default)]
+    pub asset_id: u64,
+    /// The amount of the asset to transfer, in the asset's base units
+    #[serde(rename = "aamt", default)]
+    pub amount: u64,
+    #[serde(rename = "arcv", default)]
+    pub receiver: Address,
+    /// When set, this is a clawback transaction and the assets are taken from this address
+    /// instead of the sender. The sender must be the asset's clawback account.
+    #[serde(rename = "asnd")]
+    pub asset_sender: Option<Address>,
+    /// When set, the sender's holding of the asset is removed and all remaining units
+    /// are transferred to this address.
+    #[serde(rename = "aclose")]
+    pub close_to: Option<Address>,
+}
+
+/// Fields for an asset configuration transaction
+///
+/// Creates an asset when `asset_id` is 0, reconfigures the asset when `params` is set,
+/// and destroys the asset when `params` is `None`.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+pub struct AssetConfig {
+    #[serde(rename = "caid", default)]
+    pub asset_id: u64,
+    #[serde(rename = "apar")]
+    pub params: Option<AssetParams>,
+}
+
+/// Parameters of an asset, used when creating or reconfiguring it
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
+pub struct AssetParams {
+    /// The total number of base units of the asset to create
+    #[serde(rename = "t", default)]
+    pub total: u64,
+    /// The number of digits to use after the decimal point when displaying the asset
+    #[serde(rename = "dc", default)]
+    pub decimals: u32,
+    /// Whether holdings of this asset are frozen by default
+    #[serde(rename = "df", default)]
+    pub default_frozen: bool,
+    #[serde(rename = "un", default)]
+    pub unit_name: String,
+    #[serde(rename = "an", default)]
+    pub asset_name: String,
+    /// A URL where more information about the asset can be retrieved
+    #[serde(rename = "au", default)]
+    pub url: String,
+    /// A commitment to some unspecified asset metadata
+    #[serde(rename = "am")]
+    pub metadata_hash: Option<HashDigest>,
+    /// The address which can change the asset's configuration and destroy it
+    #[serde(rename = "m")]
+    pub manager: Option<Address>,
+    /// The address holding the reserve (non-minted) units of the asset
+    #[serde(rename = "r")]
+    pub reserve: Option<Address>,
+    /// The address which can freeze or unfreeze holdings of the asset
+    #[serde(rename = "f")]
+    pub freeze: Option<Address>,
+    /// The address which can revoke holdings of the asset
+    #[serde(rename = "c")]
+    pub clawback: Option<Address>,
+}
+
+impl AssetParams {
+    /// Whether every field is unset, in which case the params are left out of the encoding entirely
+    pub fn is_empty(&self) -> bool {
+        *self == AssetParams::default()
+    }
+}
+
+/// Fields for an asset freeze transaction, sent by the asset's freeze account
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+pub struct AssetFreeze {
+    /// The account whose holding of the asset is being frozen or unfrozen
+    #[serde(rename = "fadd", default)]
+    pub freeze_account: Address,
+    #[serde(rename = "faid", default)]
+    pub asset_id: u64,
+    #[serde(rename = "afrz", default)]
+    pub frozen: bool,
+}
+
+/// Fields for an application call transaction
+///
+/// Creates an application when `app_id` is 0, otherwise calls the existing application
+/// with the action given by `on_complete`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
+pub struct ApplicationCall {
+    #[serde(rename = "apid", default)]
+    pub app_id: u64,
+    #[serde(rename = "apan", default)]
+    pub on_complete: OnCompletion,
+    #[serde(rename = "apap", with = "serde_bytes", default)]
+    pub approval_program: Vec<u8>,
+    #[serde(rename = "apsu", with = "serde_bytes", default)]
+    pub clear_state_program: Vec<u8>,
+    /// Global storage allocated to the application, only used on creation
+    #[serde(rename = "apgs", default)]
+    pub global_state_schema: StateSchema,
+    /// Local storage allocated in each opted in account, only used on creation
+    #[serde(rename = "apls", default)]
+    pub local_state_schema: StateSchema,
+    /// Additional 2048 byte pages allocated for the programs, only used on creation
+    #[serde(rename = "apep", default)]
+    pub extra_pages: u32,
+    #[serde(rename = "apaa", default, deserialize_with = "crate::util::deserialize_byte_vecs")]
+    pub app_arguments: Vec<Vec<u8>>,
+    /// Accounts whose local state may be accessed by the programs, in addition to the sender
+    #[serde(rename = "apat", default)]
+    pub accounts: Vec<Address>,
+    /// Applications whose global state may be accessed by the programs
+    #[serde(rename = "apfa", default)]
+    pub foreign_apps: Vec<u64>,
+    /// Assets whose parameters may be accessed by the programs
+    #[serde(rename = "apas", default)]
+    pub foreign_assets: Vec<u64>,
+    /// Boxes which may be accessed by the programs
+    #[serde(rename = "apbx", default)]
+    pub boxes: Vec<BoxReference>,
+}
+
+/// The action to take on an application after the approval program runs
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum OnCompletion {
+    #[default]
+    NoOp = 0,
+    OptIn = 1,
+    CloseOut = 2,
+    ClearState = 3,
+    UpdateApplication = 4,
+    DeleteApplication = 5,
+}
+
+/// Number of global or local storage slots of each kind allocated to an application
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Deserialize)]
+pub struct StateSchema {
+    #[serde(rename = "nui", default)]
+    pub num_uint: u64,
+    #[serde(rename = "nbs", default)]
+    pub num_byte_slice: u64,
+}
+
+impl StateSchema {
+    pub fn is_empty(&self) -> bool {
+        self.num_uint == 0 && self.num_byte_slice == 0
+    }
+}
+
+/// A reference to a box which an application call may access
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
+pub struct BoxReference {
+    /// 0 for a box of the called application, otherwise the 1-based index of the application in
+    /// `foreign_apps`
+    #[serde(rename = "i", default)]
+    pub app_index: u64,
+    #[serde(rename = "n", with = "serde_bytes", default)]
+    pub name: Vec<u8>,
+}
+
 impl Transaction {
     /// Creates a new transaction with a fee calculated based on `fee_per_byte`.
     pub fn new(
@@ -83,17 +299,22 @@ impl Transaction {
         fee_per_byte: MicroAlgos,
         txn_type: TransactionType,
     ) -> Result<Transaction, Error> {
+        // The fee is estimated with the per byte fee in place, so the encoding has a fee field
         let mut transaction = Transaction {
             sender: base.sender,
-            fee: MicroAlgos(0),
+            fee: fee_per_byte,
             first_valid: base.first_valid,
             last_valid: base.last_valid,
             note: base.note,
             genesis_id: base.genesis_id,
             genesis_hash: base.genesis_hash,
+            group: None,
+            rekey_to: None,
+            lease: base.lease,
             txn_type,
         };
-        transaction.fee = MIN_TXN_FEE.max(fee_per_byte * transaction.estimate_size()?);
+        let size = transaction.estimate_size(&SignerKind::Single(None))?;
+        transaction.fee = MIN_TXN_FEE.max(fee_per_byte * size);
         Ok(transaction)
     }
 
@@ -111,23 +332,149 @@ impl Transaction {
             note: base.note,
             genesis_id: base.genesis_id,
             genesis_hash: base.genesis_hash,
+            group: None,
+            rekey_to: None,
+            lease: base.lease,
             txn_type,
         }
     }
 
-    // Estimates the size of the encoded transaction, used in calculating the fee
-    fn estimate_size(&self) -> Result<u64, Error> {
-        let account = Account::generate();
-        let len = rmp_serde::to_vec_named(&account.sign_transaction(self)?)?.len() as u64;
-        Ok(len)
+    // The auth address of the transaction when authorized by `signer`, only set when it isn't the sender
+    pub(crate) fn auth_address(&self, signer: Address) -> Option<Address> {
+        if signer == self.sender {
+            None
+        } else {
+            Some(signer)
+        }
+    }
+
+    // An all zero lease is the same as no lease and is left out of the encoding
+    pub(crate) fn has_lease(&self) -> bool {
+        self.lease.is_some_and(|lease| lease.0 != [0; 32])
+    }
+
+    /// Returns the canonical encoding of the transaction prefixed with "TX", the bytes that are signed
+    pub fn bytes_to_sign(&self) -> Result<Vec<u8>, Error> {
+        let encoded_tx = rmp_serde::to_vec_named(self)?;
+        let mut prefix_encoded_tx = b"TX".to_vec();
+        prefix_encoded_tx.extend_from_slice(&encoded_tx);
+        Ok(prefix_encoded_tx)
+    }
+
+    /// Returns the raw 32 byte transaction id, the hash of the transaction's encoding
+    pub fn raw_id(&self) -> Result<HashDigest, Error> {
+        let mut id = [0; 32];
+        id.copy_from_slice(&ChecksumAlg::digest(&self.bytes_to_sign()?));
+        Ok(HashDigest(id))
+    }
+
+    /// Returns the transaction id as a base32 string, the form used by algod and indexer
+    pub fn id(&self) -> Result<String, Error> {
+        Ok(BASE32_NOPAD.encode(&self.raw_id()?.0))
+    }
+
+    /// Returns the size of the transaction once signed by `signer`, without signing it
+    ///
+    /// The signatures are replaced by placeholders of the same size, and the auth address is set
+    /// when the signer isn't the sender, so the size is exact.
+    pub fn estimate_size(&self, signer: &SignerKind) -> Result<u64, Error> {
+        let placeholder = Signature([0; 64]);
+        let mut signed_transaction = SignedTransaction {
+            logic_sig: None,
+            multisig: None,
+            auth_address: None,
+            sig: None,
+            transaction: self.clone(),
+            transaction_id: String::new(),
+        };
+        match signer {
+            SignerKind::Single(authorizer) => {
+                signed_transaction.sig = Some(placeholder);
+                signed_transaction.auth_address =
+                    authorizer.and_then(|authorizer| self.auth_address(authorizer));
+            }
+            SignerKind::Multisig(multisig) => {
+                let subsigs = multisig
+                    .public_keys
+                    .iter()
+                    .enumerate()
+                    .map(|(i, key)| MultisigSubsig {
+                        key: *key,
+                        sig: Some(placeholder).filter(|_| i < multisig.threshold as usize),
+                    })
+                    .collect();
+                signed_transaction.multisig = Some(MultisigSignature {
+                    subsigs,
+                    threshold: multisig.threshold,
+                    version: multisig.version,
+                });
+                signed_transaction.auth_address = self.auth_address(multisig.address());
+            }
+            SignerKind::LogicSig(logic_sig) => {
+                signed_transaction.logic_sig = Some(logic_sig.clone());
+                signed_transaction.auth_address = self.auth_address(logic_sig.authorizer(self));
+            }
+        }
+        Ok(rmp_serde::to_vec_named(&signed_transaction)?.len() as u64)
+    }
+}
+
+/// The preimage of a group id, a list of the hashes of the transactions in the group
+#[derive(Serialize)]
+struct TxGroup {
+    #[serde(rename = "txlist")]
+    transaction_hashes: Vec<HashDigest>,
+}
+
+/// Computes the id of an atomic group made up of the given transactions, in order
+///
+/// Any group id already set on the transactions is ignored.
+pub fn compute_group_id(transactions: &[Transaction]) -> Result<HashDigest, Error> {
+    if transactions.is_empty() {
+        return Err(Error::Validation(
+            "Can't group an empty list of transactions".to_string(),
+        ));
+    }
+    if transactions.len() > MAX_TX_GROUP_SIZE {
+        return Err(Error::Validation(format!(
+            "Transaction groups can contain at most {} transactions",
+            MAX_TX_GROUP_SIZE
+        )));
+    }
+    let mut transaction_hashes = Vec::with_capacity(transactions.len());
+    for transaction in transactions {
+        let mut transaction = transaction.clone();
+        transaction.group = None;
+        transaction_hashes.push(transaction.raw_id()?);
+    }
+    let mut prefix_encoded_group = b"TG".to_vec();
+    prefix_encoded_group.extend_from_slice(&rmp_serde::to_vec_named(&TxGroup {
+        transaction_hashes,
+    })?);
+    let mut group_id = [0; 32];
+    group_id.copy_from_slice(&ChecksumAlg::digest(&prefix_encoded_group));
+    Ok(HashDigest(group_id))
+}
+
+/// Computes the group id of the transactions and sets it on each of them, making them an atomic group
+pub fn assign_group_id(transactions: &mut [Transaction]) -> Result<HashDigest, Error> {
+    let group_id = compute_group_id(transactions)?;
+    for transaction in transactions {
+        transaction.group = Some(group_id);
     }
+    Ok(group_id)
 }
 
 /// Wraps a transaction in a signature. The encoding of this struct is suitable to be broadcast on the network
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SignedTransaction {
+    #[serde(rename = "lsig", skip_serializing_if = "Option::is_none")]
+    pub logic_sig: Option<LogicSig>,
     #[serde(rename = "msig", skip_serializing_if = "Option::is_none")]
     pub multisig: Option<MultisigSignature>,
+    /// The address that authorized the transaction, set when it differs from the sender
+    #[serde(rename = "sgnr", skip_serializing_if = "Option::is_none")]
+    pub auth_address: Option<Address>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sig: Option<Signature>,
     #[serde(rename = "txn")]
@@ -135,3 +482,32 @@ pub struct SignedTransaction {
     #[serde(skip)]
     pub transaction_id: String,
 }
+
+impl SignedTransaction {
+    /// Decodes a signed transaction from its msgpack encoding, such as the contents of a .stxn file
+    pub fn from_bytes(bytes: &[u8]) -> Result<SignedTransaction, Error> {
+        let mut signed_transaction: SignedTransaction = rmp_serde::from_slice(bytes)?;
+        signed_transaction.transaction_id = signed_transaction.transaction.id()?;
+        Ok(signed_transaction)
+    }
+
+    /// Encodes the signed transaction with the canonical msgpack encoding
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(rmp_serde::to_vec_named(self)?)
+    }
+
+    /// Verifies the signature, multisig signature or logic signature against the auth address,
+    /// or against the sender when the transaction isn't signed by a rekeyed account
+    pub fn verify(&self) -> Result<(), Error> {
+        let authorizer = self.auth_address.unwrap_or(self.transaction.sender);
+        let message = self.transaction.bytes_to_sign()?;
+        match (&self.sig, &self.multisig, &self.logic_sig) {
+            (Some(sig), None, None) => authorizer.verify_signature(&message, sig),
+            (None, Some(multisig), None) => multisig.verify(&authorizer, &message),
+            (None, None, Some(logic_sig)) => logic_sig.verify(&authorizer),
+            _ => Err(Error::Verification(
+                "Signed transaction must have exactly one kind of signature".to_string(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,616 @@
+use std::collections::HashMap;
+
+use crate::crypto::Address;
+use crate::transaction::{
+    ApplicationCall, AssetConfig, AssetFreeze, AssetParams, AssetTransfer, BoxReference,
+    KeyRegistration, OnCompletion, Payment, SignerKind, StateSchema, Transaction, TransactionType,
+    MAX_TX_GROUP_SIZE, MIN_TXN_FEE,
+};
+use crate::{algod, algod_v2};
+use crate::{Error, HashDigest, Lease, MicroAlgos, Round, VotePK, VRFPK};
+
+/// The maximum number of rounds between the first and last valid rounds of a transaction, also
+/// the validity window of transactions built from the params suggested by algod
+pub const MAX_TXN_LIFE: u64 = 1000;
+
+/// The fee, validity window and genesis of new transactions, usually suggested by algod
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SuggestedParams {
+    /// The fee per byte of the signed transaction, or the whole fee when `flat_fee` is set
+    pub fee: MicroAlgos,
+    pub flat_fee: bool,
+    /// The minimum fee of a transaction, a fee calculated from the fee per byte is raised to it
+    pub min_fee: MicroAlgos,
+    pub first_valid: Round,
+    pub last_valid: Round,
+    pub genesis_id: String,
+    pub genesis_hash: HashDigest,
+}
+
+impl From<algod_v2::models::TransactionParams> for SuggestedParams {
+    fn from(params: algod_v2::models::TransactionParams) -> Self {
+        SuggestedParams {
+            fee: params.fee,
+            flat_fee: false,
+            min_fee: params.min_fee,
+            first_valid: params.last_round,
+            last_valid: params.last_round + MAX_TXN_LIFE,
+            genesis_id: params.genesis_id,
+            genesis_hash: params.genesis_hash,
+        }
+    }
+}
+
+impl From<algod::models::TransactionParams> for SuggestedParams {
+    fn from(params: algod::models::TransactionParams) -> Self {
+        SuggestedParams {
+            fee: params.fee,
+            flat_fee: false,
+            min_fee: MIN_TXN_FEE,
+            first_valid: params.last_round,
+            last_valid: params.last_round + MAX_TXN_LIFE,
+            genesis_id: params.genesis_id,
+            genesis_hash: params.genesis_hash,
+        }
+    }
+}
+
+/// Builds a transaction from suggested params, the type specific fields are set through one of
+/// the aliases such as [PaymentBuilder]
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder<T> {
+    sender: Address,
+    params: SuggestedParams,
+    note: Vec<u8>,
+    lease: Option<Lease>,
+    rekey_to: Option<Address>,
+    signer: SignerKind,
+    fields: T,
+}
+
+/// Builds a payment, the receiver must be set
+pub type PaymentBuilder = TransactionBuilder<PaymentFields>;
+/// Builds a key registration, every participation field must be set
+pub type KeyRegBuilder = TransactionBuilder<KeyRegFields>;
+/// Builds an asset transfer, the asset id and receiver must be set
+pub type AssetTransferBuilder = TransactionBuilder<AssetTransferFields>;
+/// Builds an asset configuration, creating an asset unless its id is set
+pub type AssetConfigBuilder = TransactionBuilder<AssetConfig>;
+/// Builds an asset freeze, the asset id and freeze account must be set
+pub type AssetFreezeBuilder = TransactionBuilder<AssetFreezeFields>;
+/// Builds an application call, creating an application unless its id is set
+pub type ApplicationCallBuilder = TransactionBuilder<ApplicationCall>;
+
+impl<T> TransactionBuilder<T> {
+    fn with_fields(sender: Address, params: &SuggestedParams, fields: T) -> Self {
+        TransactionBuilder {
+            sender,
+            params: params.clone(),
+            note: Vec::new(),
+            lease: None,
+            rekey_to: None,
+            signer: SignerKind::default(),
+            fields,
+        }
+    }
+
+    pub fn note(mut self, note: Vec<u8>) -> Self {
+        self.note = note;
+        self
+    }
+
+    /// Sets the lease of the transaction, an all zero lease is the same as no lease and is dropped
+    pub fn lease(mut self, lease: Lease) -> Self {
+        self.lease = Some(lease).filter(|lease| lease.0 != [0; 32]);
+        self
+    }
+
+    /// Rekeys the sender so that its transactions must afterwards be authorized by `address`
+    pub fn rekey_to(mut self, address: Address) -> Self {
+        self.rekey_to = Some(address);
+        self
+    }
+
+    /// Overrides the suggested fee with a flat fee
+    pub fn flat_fee(mut self, fee: MicroAlgos) -> Self {
+        self.params.fee = fee;
+        self.params.flat_fee = true;
+        self
+    }
+
+    /// Overrides the suggested fee with a fee per byte, raised to the minimum fee
+    pub fn fee_per_byte(mut self, fee: MicroAlgos) -> Self {
+        self.params.fee = fee;
+        self.params.flat_fee = false;
+        self
+    }
+
+    /// How the transaction will be signed, which the fee per byte is charged for, a single
+    /// signature by the sender by default
+    pub fn signer(mut self, signer: SignerKind) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    pub fn first_valid(mut self, round: Round) -> Self {
+        self.params.first_valid = round;
+        self
+    }
+
+    pub fn last_valid(mut self, round: Round) -> Self {
+        self.params.last_valid = round;
+        self
+    }
+
+    fn finish(self, txn_type: TransactionType) -> Result<Transaction, Error> {
+        let (first_valid, last_valid) = (self.params.first_valid, self.params.last_valid);
+        if last_valid.0 < first_valid.0 {
+            return Err(Error::Validation(format!(
+                "Last valid round {} is before first valid round {}",
+                last_valid, first_valid
+            )));
+        }
+        if last_valid.0 - first_valid.0 > MAX_TXN_LIFE {
+            return Err(Error::Validation(format!(
+                "Transactions can be valid for at most {} rounds",
+                MAX_TXN_LIFE
+            )));
+        }
+        let mut transaction = Transaction {
+            sender: self.sender,
+            fee: self.params.fee,
+            first_valid,
+            last_valid,
+            note: self.note,
+            genesis_id: self.params.genesis_id,
+            genesis_hash: self.params.genesis_hash,
+            group: None,
+            rekey_to: self.rekey_to,
+            lease: self.lease,
+            txn_type,
+        };
+        transaction.fee = if self.params.flat_fee {
+            self.params.fee
+        } else {
+            let fee = self.params.fee * transaction.estimate_size(&self.signer)?;
+            self.params.min_fee.max(fee)
+        };
+        Ok(transaction)
+    }
+}
+
+// Fails with a validation error naming the field when a required field isn't set
+fn required<V>(value: Option<V>, name: &str) -> Result<V, Error> {
+    value.ok_or_else(|| Error::Validation(format!("{} must be set", name)))
+}
+
+/// The fields of a [PaymentBuilder]
+#[derive(Debug, Clone, Default)]
+pub struct PaymentFields {
+    receiver: Option<Address>,
+    amount: MicroAlgos,
+    close_remainder_to: Option<Address>,
+}
+
+impl PaymentBuilder {
+    pub fn new(sender: Address, params: &SuggestedParams) -> PaymentBuilder {
+        TransactionBuilder::with_fields(sender, params, PaymentFields::default())
+    }
+
+    pub fn receiver(mut self, receiver: Address) -> Self {
+        self.fields.receiver = Some(receiver);
+        self
+    }
+
+    pub fn amount(mut self, amount: MicroAlgos) -> Self {
+        self.fields.amount = amount;
+        self
+    }
+
+    /// Closes the sender's account, sending all of its remaining funds to `address`
+    pub fn close_remainder_to(mut self, address: Address) -> Self {
+        self.fields.close_remainder_to = Some(address);
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, Error> {
+        let payment = Payment {
+            amount: self.fields.amount,
+            receiver: required(self.fields.receiver, "Payment receiver")?,
+            close_remainder_to: self.fields.close_remainder_to,
+        };
+        self.finish(TransactionType::Payment(payment))
+    }
+}
+
+/// The fields of a [KeyRegBuilder]
+#[derive(Debug, Clone, Default)]
+pub struct KeyRegFields {
+    vote_pk: Option<VotePK>,
+    selection_pk: Option<VRFPK>,
+    vote_first: Option<Round>,
+    vote_last: Option<Round>,
+    vote_key_dilution: Option<u64>,
+}
+
+impl KeyRegBuilder {
+    pub fn new(sender: Address, params: &SuggestedParams) -> KeyRegBuilder {
+        TransactionBuilder::with_fields(sender, params, KeyRegFields::default())
+    }
+
+    pub fn vote_pk(mut self, vote_pk: VotePK) -> Self {
+        self.fields.vote_pk = Some(vote_pk);
+        self
+    }
+
+    pub fn selection_pk(mut self, selection_pk: VRFPK) -> Self {
+        self.fields.selection_pk = Some(selection_pk);
+        self
+    }
+
+    /// The rounds the participation key is valid for
+    pub fn vote_rounds(mut self, vote_first: Round, vote_last: Round) -> Self {
+        self.fields.vote_first = Some(vote_first);
+        self.fields.vote_last = Some(vote_last);
+        self
+    }
+
+    pub fn vote_key_dilution(mut self, vote_key_dilution: u64) -> Self {
+        self.fields.vote_key_dilution = Some(vote_key_dilution);
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, Error> {
+        let key_registration = KeyRegistration {
+            vote_pk: required(self.fields.vote_pk, "Vote public key")?,
+            selection_pk: required(self.fields.selection_pk, "Selection public key")?,
+            vote_first: required(self.fields.vote_first, "Vote first round")?,
+            vote_last: required(self.fields.vote_last, "Vote last round")?,
+            vote_key_dilution: required(self.fields.vote_key_dilution, "Vote key dilution")?,
+        };
+        if key_registration.vote_last.0 < key_registration.vote_first.0 {
+            return Err(Error::Validation(
+                "Vote last round is before vote first round".to_string(),
+            ));
+        }
+        self.finish(TransactionType::KeyRegistration(key_registration))
+    }
+}
+
+/// The fields of an [AssetTransferBuilder]
+#[derive(Debug, Clone, Default)]
+pub struct AssetTransferFields {
+    asset_id: Option<u64>,
+    receiver: Option<Address>,
+    amount: u64,
+    asset_sender: Option<Address>,
+    close_to: Option<Address>,
+}
+
+impl AssetTransferBuilder {
+    pub fn new(sender: Address, params: &SuggestedParams) -> AssetTransferBuilder {
+        TransactionBuilder::with_fields(sender, params, AssetTransferFields::default())
+    }
+
+    /// Opts the sender in to the asset, a transfer of nothing to itself
+    pub fn opt_in(
+        sender: Address,
+        params: &SuggestedParams,
+        asset_id: u64,
+    ) -> AssetTransferBuilder {
+        AssetTransferBuilder::new(sender, params)
+            .asset_id(asset_id)
+            .receiver(sender)
+    }
+
+    pub fn asset_id(mut self, asset_id: u64) -> Self {
+        self.fields.asset_id = Some(asset_id);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Address) -> Self {
+        self.fields.receiver = Some(receiver);
+        self
+    }
+
+    /// The amount of the asset to transfer, in the asset's base units
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.fields.amount = amount;
+        self
+    }
+
+    /// Revokes the assets from `address`, the sender must be the asset's clawback account
+    pub fn asset_sender(mut self, address: Address) -> Self {
+        self.fields.asset_sender = Some(address);
+        self
+    }
+
+    /// Removes the sender's holding of the asset, sending all remaining units to `address`
+    pub fn close_to(mut self, address: Address) -> Self {
+        self.fields.close_to = Some(address);
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, Error> {
+        let asset_transfer = AssetTransfer {
+            asset_id: required(self.fields.asset_id, "Asset id")?,
+            amount: self.fields.amount,
+            receiver: required(self.fields.receiver, "Asset receiver")?,
+            asset_sender: self.fields.asset_sender,
+            close_to: self.fields.close_to,
+        };
+        self.finish(TransactionType::AssetTransfer(asset_transfer))
+    }
+}
+
+impl AssetConfigBuilder {
+    pub fn new(sender: Address, params: &SuggestedParams) -> AssetConfigBuilder {
+        let asset_config = AssetConfig {
+            asset_id: 0,
+            params: None,
+        };
+        TransactionBuilder::with_fields(sender, params, asset_config)
+    }
+
+    /// The asset to reconfigure or destroy, leave it unset to create an asset
+    pub fn asset_id(mut self, asset_id: u64) -> Self {
+        self.fields.asset_id = asset_id;
+        self
+    }
+
+    /// The params of the asset to create or reconfigure, leave them unset to destroy the asset
+    pub fn params(mut self, params: AssetParams) -> Self {
+        self.fields.params = Some(params).filter(|params| !params.is_empty());
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, Error> {
+        if self.fields.asset_id == 0 && self.fields.params.is_none() {
+            return Err(Error::Validation(
+                "Asset params must be set to create an asset".to_string(),
+            ));
+        }
+        let asset_config = self.fields.clone();
+        self.finish(TransactionType::AssetConfig(asset_config))
+    }
+}
+
+/// The fields of an [AssetFreezeBuilder]
+#[derive(Debug, Clone, Default)]
+pub struct AssetFreezeFields {
+    asset_id: Option<u64>,
+    freeze_account: Option<Address>,
+    frozen: bool,
+}
+
+impl AssetFreezeBuilder {
+    pub fn new(sender: Address, params: &SuggestedParams) -> AssetFreezeBuilder {
+        TransactionBuilder::with_fields(sender, params, AssetFreezeFields::default())
+    }
+
+    pub fn asset_id(mut self, asset_id: u64) -> Self {
+        self.fields.asset_id = Some(asset_id);
+        self
+    }
+
+    /// The account whose holding of the asset is frozen or unfrozen
+    pub fn freeze_account(mut self, address: Address) -> Self {
+        self.fields.freeze_account = Some(address);
+        self
+    }
+
+    pub fn frozen(mut self, frozen: bool) -> Self {
+        self.fields.frozen = frozen;
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, Error> {
+        let asset_freeze = AssetFreeze {
+            freeze_account: required(self.fields.freeze_account, "Freeze account")?,
+            asset_id: required(self.fields.asset_id, "Asset id")?,
+            frozen: self.fields.frozen,
+        };
+        self.finish(TransactionType::AssetFreeze(asset_freeze))
+    }
+}
+
+impl ApplicationCallBuilder {
+    pub fn new(sender: Address, params: &SuggestedParams) -> ApplicationCallBuilder {
+        TransactionBuilder::with_fields(sender, params, ApplicationCall::default())
+    }
+
+    /// The application to call, leave it unset to create an application
+    pub fn app_id(mut self, app_id: u64) -> Self {
+        self.fields.app_id = app_id;
+        self
+    }
+
+    pub fn on_complete(mut self, on_complete: OnCompletion) -> Self {
+        self.fields.on_complete = on_complete;
+        self
+    }
+
+    /// The programs of the application to create or update
+    pub fn programs(mut self, approval_program: Vec<u8>, clear_state_program: Vec<u8>) -> Self {
+        self.fields.approval_program = approval_program;
+        self.fields.clear_state_program = clear_state_program;
+        self
+    }
+
+    /// The storage of the application to create, in its global state and in each opted in account
+    pub fn state_schemas(mut self, global: StateSchema, local: StateSchema) -> Self {
+        self.fields.global_state_schema = global;
+        self.fields.local_state_schema = local;
+        self
+    }
+
+    pub fn extra_pages(mut self, extra_pages: u32) -> Self {
+        self.fields.extra_pages = extra_pages;
+        self
+    }
+
+    pub fn app_arguments(mut self, app_arguments: Vec<Vec<u8>>) -> Self {
+        self.fields.app_arguments = app_arguments;
+        self
+    }
+
+    pub fn accounts(mut self, accounts: Vec<Address>) -> Self {
+        self.fields.accounts = accounts;
+        self
+    }
+
+    pub fn foreign_apps(mut self, foreign_apps: Vec<u64>) -> Self {
+        self.fields.foreign_apps = foreign_apps;
+        self
+    }
+
+    pub fn foreign_assets(mut self, foreign_assets: Vec<u64>) -> Self {
+        self.fields.foreign_assets = foreign_assets;
+        self
+    }
+
+    pub fn boxes(mut self, boxes: Vec<BoxReference>) -> Self {
+        self.fields.boxes = boxes;
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, Error> {
+        let call = &self.fields;
+        let has_programs =
+            !call.approval_program.is_empty() || !call.clear_state_program.is_empty();
+        if call.app_id == 0 {
+            if call.approval_program.is_empty() || call.clear_state_program.is_empty() {
+                return Err(Error::Validation(
+                    "Both programs must be set to create an application".to_string(),
+                ));
+            }
+        } else {
+            if has_programs && call.on_complete != OnCompletion::UpdateApplication {
+                return Err(Error::Validation(
+                    "Programs can only be set when creating or updating an application".to_string(),
+                ));
+            }
+            if !call.global_state_schema.is_empty()
+                || !call.local_state_schema.is_empty()
+                || call.extra_pages != 0
+            {
+                return Err(Error::Validation(
+                    "State schemas and extra pages can only be set when creating an application"
+                        .to_string(),
+                ));
+            }
+        }
+        let application_call = self.fields.clone();
+        self.finish(TransactionType::ApplicationCall(application_call))
+    }
+}
+
+/// Pools the fees of an atomic group into one payer transaction, leaving the other transactions
+/// of the group without a fee
+#[derive(Debug, Clone)]
+pub struct GroupFeePlanner {
+    params: SuggestedParams,
+    payer: usize,
+    signers: HashMap<usize, SignerKind>,
+    inner_transactions: HashMap<usize, u64>,
+}
+
+impl GroupFeePlanner {
+    /// Plans the fees of a group with the suggested params, paid by the transaction at `payer`
+    pub fn new(params: &SuggestedParams, payer: usize) -> GroupFeePlanner {
+        GroupFeePlanner {
+            params: params.clone(),
+            payer,
+            signers: HashMap::new(),
+            inner_transactions: HashMap::new(),
+        }
+    }
+
+    /// How the transaction at `index` will be signed, which the fee per byte is charged for, a
+    /// single signature by the sender by default
+    pub fn signer(mut self, index: usize, signer: SignerKind) -> Self {
+        self.signers.insert(index, signer);
+        self
+    }
+
+    /// The number of inner transactions issued by the application call at `index`, each of them
+    /// is charged the minimum fee
+    pub fn inner_transactions(mut self, index: usize, count: u64) -> Self {
+        self.inner_transactions.insert(index, count);
+        self
+    }
+
+    /// The fee the payer must pay for the whole group
+    pub fn total_fee(&self, transactions: &[Transaction]) -> Result<MicroAlgos, Error> {
+        if transactions.is_empty() {
+            return Err(Error::Validation(
+                "Can't pool the fees of an empty list of transactions".to_string(),
+            ));
+        }
+        if transactions.len() > MAX_TX_GROUP_SIZE {
+            return Err(Error::Validation(format!(
+                "Transaction groups can contain at most {} transactions",
+                MAX_TX_GROUP_SIZE
+            )));
+        }
+        let indexes = self
+            .signers
+            .keys()
+            .chain(self.inner_transactions.keys())
+            .chain(std::iter::once(&self.payer));
+        for &index in indexes {
+            if index >= transactions.len() {
+                return Err(Error::Validation(format!(
+                    "Transaction {} is not in the group of {} transactions",
+                    index,
+                    transactions.len()
+                )));
+            }
+        }
+        let mut total = MicroAlgos(0);
+        for (index, transaction) in transactions.iter().enumerate() {
+            total = total + self.fee(transaction, index)?;
+        }
+        Ok(total)
+    }
+
+    /// Sets the fee of the payer to the total fee of the group and the fees of the other
+    /// transactions to zero, the group id must be assigned afterwards
+    pub fn apply(&self, transactions: &mut [Transaction]) -> Result<MicroAlgos, Error> {
+        let total = self.total_fee(transactions)?;
+        for (index, transaction) in transactions.iter_mut().enumerate() {
+            transaction.fee = if index == self.payer {
+                total
+            } else {
+                MicroAlgos(0)
+            };
+        }
+        Ok(total)
+    }
+
+    // The fee the transaction would need if it paid for itself, plus its inner transactions
+    fn fee(&self, transaction: &Transaction, index: usize) -> Result<MicroAlgos, Error> {
+        let fee = if self.params.flat_fee {
+            self.params.fee
+        } else {
+            let mut transaction = transaction.clone();
+            transaction.fee = self.params.fee;
+            let signer = self.signers.get(&index).cloned().unwrap_or_default();
+            let fee = self.params.fee * transaction.estimate_size(&signer)?;
+            self.params.min_fee.max(fee)
+        };
+        let inner_transactions = self.inner_transactions.get(&index).copied().unwrap_or(0);
+        if inner_transactions == 0 {
+            return Ok(fee);
+        }
+        match transaction.txn_type {
+            TransactionType::ApplicationCall(_) => {
+                Ok(fee + self.params.min_fee * inner_transactions)
+            }
+            _ => Err(Error::Validation(format!(
+                "Transaction {} is not an application call and can't issue inner transactions",
+                index
+            ))),
+        }
+    }
+}
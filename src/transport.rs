@@ -0,0 +1,209 @@
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{Error, HttpError};
+
+/// HTTP methods used by the REST APIs
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+    Delete,
+}
+
+impl Method {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+/// A request to one of the REST APIs, the url includes the query string
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    pub(crate) fn new(method: Method, url: String) -> HttpRequest {
+        HttpRequest {
+            method,
+            url,
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub(crate) fn header(mut self, name: &str, value: &str) -> HttpRequest {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub(crate) fn headers(mut self, headers: &[(String, String)]) -> HttpRequest {
+        self.headers.extend_from_slice(headers);
+        self
+    }
+
+    /// Appends the url encoded query to the url, leaving it untouched when the query is empty
+    pub(crate) fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Result<HttpRequest, Error> {
+        let query =
+            serde_urlencoded::to_string(query).map_err(|err| Error::Validation(err.to_string()))?;
+        if !query.is_empty() {
+            self.url.push('?');
+            self.url.push_str(&query);
+        }
+        Ok(self)
+    }
+
+    pub(crate) fn body(mut self, content_type: &str, body: Vec<u8>) -> HttpRequest {
+        self.body = Some(body);
+        self.header("Content-Type", content_type)
+    }
+
+    pub(crate) fn json<B: Serialize + ?Sized>(self, body: &B) -> Result<HttpRequest, Error> {
+        Ok(self.body("application/json", serde_json::to_vec(body)?))
+    }
+}
+
+/// The response to an `HttpRequest`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Turns a response with an error status into an error holding the status and message
+    pub(crate) fn error_for_status(self) -> Result<HttpResponse, Error> {
+        if (200..300).contains(&self.status) {
+            Ok(self)
+        } else {
+            Err(HttpError::from_response(self.status, &self.body).into())
+        }
+    }
+
+    pub(crate) fn json<T: DeserializeOwned>(self) -> Result<T, Error> {
+        Ok(serde_json::from_slice(&self.error_for_status()?.body)?)
+    }
+}
+
+/// Sends the requests of the clients, implement it to use another HTTP stack or a mock
+pub trait HttpTransport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+/// The default transport of the blocking clients
+#[derive(Clone, Debug)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> ReqwestTransport {
+        ReqwestTransport::with_client(reqwest::blocking::Client::new())
+    }
+
+    /// Sends the requests with a configured client, e.g. with a proxy or custom root certificates
+    pub fn with_client(client: reqwest::blocking::Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> ReqwestTransport {
+        ReqwestTransport::new()
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let method = match request.method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+            Method::Delete => reqwest::Method::DELETE,
+        };
+        let mut builder = self.client.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        let mut response = builder.send()?;
+        let mut body = Vec::new();
+        response.copy_to(&mut body)?;
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            body,
+        })
+    }
+}
+
+/// Sends the requests of the async clients, available with the `async` feature
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncHttpTransport: Send + Sync {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+/// The default transport of the async clients, available with the `async` feature
+#[cfg(feature = "async")]
+#[derive(Clone, Debug, Default)]
+pub struct AsyncReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl AsyncReqwestTransport {
+    pub fn new() -> AsyncReqwestTransport {
+        AsyncReqwestTransport::default()
+    }
+
+    /// Sends the requests with a configured client, e.g. with a proxy or custom root certificates
+    pub fn with_client(client: reqwest::Client) -> AsyncReqwestTransport {
+        AsyncReqwestTransport { client }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncHttpTransport for AsyncReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let method = match request.method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+            Method::Delete => reqwest::Method::DELETE,
+        };
+        let mut builder = self.client.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+        let body = response.bytes().await?.to_vec();
+        Ok(HttpResponse { status, body })
+    }
+}
+
+/// Converts the custom headers given to a client into the headers of its requests
+pub(crate) fn header_pairs(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.as_str().to_string(),
+                String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            )
+        })
+        .collect()
+}
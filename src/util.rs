@@ -1,7 +1,12 @@
 //! This file mostly just hides away various trait implementations that would clutter up and distract from the more important code elsewhere
-use crate::crypto::{Address, MultisigSignature, MultisigSubsig, Signature};
+use crate::crypto::{
+    Address, LogicSig, MultisigAddress, MultisigSignature, MultisigSubsig, Signature,
+};
 use crate::kmd::responses::ExportKeyResponse;
-use crate::{Ed25519PublicKey, HashDigest, MasterDerivationKey, MicroAlgos, Round, VotePK, VRFPK};
+use crate::{
+    DecodingError, Ed25519PublicKey, HashDigest, Lease, MasterDerivationKey, MicroAlgos, Round,
+    VotePK, VRFPK,
+};
 use data_encoding::BASE64;
 use serde::de::Visitor;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -9,7 +14,10 @@ use static_assertions::_core::ops::{Add, Sub};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Mul;
-use crate::transaction::{TransactionType, Transaction};
+use std::str::FromStr;
+use crate::transaction::{
+    AssetParams, BoxReference, OnCompletion, StateSchema, Transaction, TransactionType,
+};
 
 impl Serialize for Transaction {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
@@ -26,35 +34,202 @@ impl Serialize for Transaction {
                 } + if payment.amount.0 != 0 { 1 } else { 0 }
             }
             TransactionType::KeyRegistration(_) => 5,
+            TransactionType::AssetConfig(asset_config) => {
+                let has_params = asset_config
+                    .params
+                    .as_ref()
+                    .is_some_and(|params| !params.is_empty());
+                (if asset_config.asset_id != 0 { 1 } else { 0 }) + if has_params { 1 } else { 0 }
+            }
+            TransactionType::ApplicationCall(application_call) => {
+                (if application_call.app_arguments.is_empty() { 0 } else { 1 })
+                    + if application_call.on_complete == OnCompletion::NoOp { 0 } else { 1 }
+                    + if application_call.approval_program.is_empty() { 0 } else { 1 }
+                    + if application_call.foreign_assets.is_empty() { 0 } else { 1 }
+                    + if application_call.accounts.is_empty() { 0 } else { 1 }
+                    + if application_call.boxes.is_empty() { 0 } else { 1 }
+                    + if application_call.extra_pages == 0 { 0 } else { 1 }
+                    + if application_call.foreign_apps.is_empty() { 0 } else { 1 }
+                    + if application_call.global_state_schema.is_empty() { 0 } else { 1 }
+                    + if application_call.app_id == 0 { 0 } else { 1 }
+                    + if application_call.local_state_schema.is_empty() { 0 } else { 1 }
+                    + if application_call.clear_state_program.is_empty() { 0 } else { 1 }
+            }
+            TransactionType::AssetFreeze(asset_freeze) => {
+                (if asset_freeze.freeze_account.0 != [0; 32] { 1 } else { 0 })
+                    + if asset_freeze.asset_id != 0 { 1 } else { 0 }
+                    + if asset_freeze.frozen { 1 } else { 0 }
+            }
+            TransactionType::AssetTransfer(asset_transfer) => {
+                (if asset_transfer.asset_id != 0 { 1 } else { 0 })
+                    + if asset_transfer.receiver.0 != [0; 32] { 1 } else { 0 }
+                    + if asset_transfer.amount != 0 { 1 } else { 0 }
+                    + if asset_transfer.asset_sender.is_some() { 1 } else { 0 }
+                    + if asset_transfer.close_to.is_some() { 1 } else { 0 }
+            }
         };
-        let len = 6
+        let len = 4
             + type_len
+            + if self.fee.0 != 0 { 1 } else { 0 }
+            + if self.first_valid.0 != 0 { 1 } else { 0 }
             + if self.note.is_empty() { 0 } else { 1 }
-            + if self.genesis_id.is_empty() { 0 } else { 1 };
+            + if self.genesis_id.is_empty() { 0 } else { 1 }
+            + if self.group.is_some() { 1 } else { 0 }
+            + if self.rekey_to.is_some() { 1 } else { 0 }
+            + if self.has_lease() { 1 } else { 0 };
         let mut state = serializer.serialize_struct("Transaction", len)?;
+        if let TransactionType::AssetTransfer(asset_transfer) = &self.txn_type {
+            if asset_transfer.amount != 0 {
+                state.serialize_field("aamt", &asset_transfer.amount)?;
+            }
+        }
+        if let TransactionType::AssetTransfer(asset_transfer) = &self.txn_type {
+            if asset_transfer.close_to.is_some() {
+                state.serialize_field("aclose", &asset_transfer.close_to)?;
+            }
+        }
+        if let TransactionType::AssetFreeze(asset_freeze) = &self.txn_type {
+            if asset_freeze.frozen {
+                state.serialize_field("afrz", &asset_freeze.frozen)?;
+            }
+        }
         if let TransactionType::Payment(payment) = &self.txn_type {
             if payment.amount.0 != 0 {
                 state.serialize_field("amt", &payment.amount)?;
             }
         }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.app_arguments.is_empty() {
+                let app_arguments: Vec<_> = application_call
+                    .app_arguments
+                    .iter()
+                    .map(|argument| serde_bytes::Bytes::new(argument))
+                    .collect();
+                state.serialize_field("apaa", &app_arguments)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if application_call.on_complete != OnCompletion::NoOp {
+                state.serialize_field("apan", &application_call.on_complete)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.approval_program.is_empty() {
+                state.serialize_field(
+                    "apap",
+                    serde_bytes::Bytes::new(&application_call.approval_program),
+                )?;
+            }
+        }
+        if let TransactionType::AssetConfig(asset_config) = &self.txn_type {
+            if let Some(params) = asset_config.params.as_ref().filter(|params| !params.is_empty()) {
+                state.serialize_field("apar", params)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.foreign_assets.is_empty() {
+                state.serialize_field("apas", &application_call.foreign_assets)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.accounts.is_empty() {
+                state.serialize_field("apat", &application_call.accounts)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.boxes.is_empty() {
+                state.serialize_field("apbx", &application_call.boxes)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if application_call.extra_pages != 0 {
+                state.serialize_field("apep", &application_call.extra_pages)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.foreign_apps.is_empty() {
+                state.serialize_field("apfa", &application_call.foreign_apps)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.global_state_schema.is_empty() {
+                state.serialize_field("apgs", &application_call.global_state_schema)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if application_call.app_id != 0 {
+                state.serialize_field("apid", &application_call.app_id)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.local_state_schema.is_empty() {
+                state.serialize_field("apls", &application_call.local_state_schema)?;
+            }
+        }
+        if let TransactionType::ApplicationCall(application_call) = &self.txn_type {
+            if !application_call.clear_state_program.is_empty() {
+                state.serialize_field(
+                    "apsu",
+                    serde_bytes::Bytes::new(&application_call.clear_state_program),
+                )?;
+            }
+        }
+        if let TransactionType::AssetTransfer(asset_transfer) = &self.txn_type {
+            if asset_transfer.receiver.0 != [0; 32] {
+                state.serialize_field("arcv", &asset_transfer.receiver)?;
+            }
+        }
+        if let TransactionType::AssetTransfer(asset_transfer) = &self.txn_type {
+            if asset_transfer.asset_sender.is_some() {
+                state.serialize_field("asnd", &asset_transfer.asset_sender)?;
+            }
+        }
+        if let TransactionType::AssetConfig(asset_config) = &self.txn_type {
+            if asset_config.asset_id != 0 {
+                state.serialize_field("caid", &asset_config.asset_id)?;
+            }
+        }
         if let TransactionType::Payment(payment) = &self.txn_type {
             if payment.close_remainder_to.is_some() {
                 state.serialize_field("close", &payment.close_remainder_to)?;
             }
         }
-        state.serialize_field("fee", &self.fee)?;
-        state.serialize_field("fv", &self.first_valid)?;
+        if let TransactionType::AssetFreeze(asset_freeze) = &self.txn_type {
+            if asset_freeze.freeze_account.0 != [0; 32] {
+                state.serialize_field("fadd", &asset_freeze.freeze_account)?;
+            }
+        }
+        if let TransactionType::AssetFreeze(asset_freeze) = &self.txn_type {
+            if asset_freeze.asset_id != 0 {
+                state.serialize_field("faid", &asset_freeze.asset_id)?;
+            }
+        }
+        if self.fee.0 != 0 {
+            state.serialize_field("fee", &self.fee)?;
+        }
+        if self.first_valid.0 != 0 {
+            state.serialize_field("fv", &self.first_valid)?;
+        }
         if !self.genesis_id.is_empty() {
             state.serialize_field("gen", &self.genesis_id)?;
         }
         state.serialize_field("gh", &self.genesis_hash)?;
+        if self.group.is_some() {
+            state.serialize_field("grp", &self.group)?;
+        }
         state.serialize_field("lv", &self.last_valid)?;
+        if self.has_lease() {
+            state.serialize_field("lx", &self.lease)?;
+        }
         if !self.note.is_empty() {
             state.serialize_field("note", &serde_bytes::ByteBuf::from(self.note.clone()))?;
         }
         if let TransactionType::Payment(payment) = &self.txn_type {
             state.serialize_field("rcv", &payment.receiver)?;
         }
+        if self.rekey_to.is_some() {
+            state.serialize_field("rekey", &self.rekey_to)?;
+        }
         if let TransactionType::KeyRegistration(key_registration) = &self.txn_type {
             state.serialize_field("selkey", &key_registration.selection_pk)?;
         }
@@ -66,6 +241,18 @@ impl Serialize for Transaction {
             TransactionType::KeyRegistration(_key_registration) => {
                 state.serialize_field("type", "keyreg")?;
             }
+            TransactionType::AssetTransfer(_asset_transfer) => {
+                state.serialize_field("type", "axfer")?;
+            }
+            TransactionType::AssetConfig(_asset_config) => {
+                state.serialize_field("type", "acfg")?;
+            }
+            TransactionType::AssetFreeze(_asset_freeze) => {
+                state.serialize_field("type", "afrz")?;
+            }
+            TransactionType::ApplicationCall(_application_call) => {
+                state.serialize_field("type", "appl")?;
+            }
         }
         if let TransactionType::KeyRegistration(key_registration) = &self.txn_type {
             state.serialize_field("votefst", &key_registration.vote_first)?;
@@ -79,6 +266,130 @@ impl Serialize for Transaction {
         if let TransactionType::KeyRegistration(key_registration) = &self.txn_type {
             state.serialize_field("votelst", &key_registration.vote_last)?;
         }
+        if let TransactionType::AssetTransfer(asset_transfer) = &self.txn_type {
+            if asset_transfer.asset_id != 0 {
+                state.serialize_field("xaid", &asset_transfer.asset_id)?;
+            }
+        }
+        state.end()
+    }
+}
+
+impl Serialize for AssetParams {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeMap;
+        let len = if self.metadata_hash.is_some() { 1 } else { 0 }
+            + if self.asset_name.is_empty() { 0 } else { 1 }
+            + if self.url.is_empty() { 0 } else { 1 }
+            + if self.clawback.is_some() { 1 } else { 0 }
+            + if self.decimals != 0 { 1 } else { 0 }
+            + if self.default_frozen { 1 } else { 0 }
+            + if self.freeze.is_some() { 1 } else { 0 }
+            + if self.manager.is_some() { 1 } else { 0 }
+            + if self.reserve.is_some() { 1 } else { 0 }
+            + if self.total != 0 { 1 } else { 0 }
+            + if self.unit_name.is_empty() { 0 } else { 1 };
+        let mut state = serializer.serialize_map(Some(len))?;
+        if let Some(metadata_hash) = &self.metadata_hash {
+            state.serialize_entry("am", metadata_hash)?;
+        }
+        if !self.asset_name.is_empty() {
+            state.serialize_entry("an", &self.asset_name)?;
+        }
+        if !self.url.is_empty() {
+            state.serialize_entry("au", &self.url)?;
+        }
+        if let Some(clawback) = &self.clawback {
+            state.serialize_entry("c", clawback)?;
+        }
+        if self.decimals != 0 {
+            state.serialize_entry("dc", &self.decimals)?;
+        }
+        if self.default_frozen {
+            state.serialize_entry("df", &self.default_frozen)?;
+        }
+        if let Some(freeze) = &self.freeze {
+            state.serialize_entry("f", freeze)?;
+        }
+        if let Some(manager) = &self.manager {
+            state.serialize_entry("m", manager)?;
+        }
+        if let Some(reserve) = &self.reserve {
+            state.serialize_entry("r", reserve)?;
+        }
+        if self.total != 0 {
+            state.serialize_entry("t", &self.total)?;
+        }
+        if !self.unit_name.is_empty() {
+            state.serialize_entry("un", &self.unit_name)?;
+        }
+        state.end()
+    }
+}
+
+impl Serialize for OnCompletion {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(*self as u64)
+    }
+}
+
+impl<'de> Deserialize<'de> for OnCompletion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        match u64::deserialize(deserializer)? {
+            0 => Ok(OnCompletion::NoOp),
+            1 => Ok(OnCompletion::OptIn),
+            2 => Ok(OnCompletion::CloseOut),
+            3 => Ok(OnCompletion::ClearState),
+            4 => Ok(OnCompletion::UpdateApplication),
+            5 => Ok(OnCompletion::DeleteApplication),
+            other => Err(D::Error::custom(format!("Invalid on completion action: {}", other))),
+        }
+    }
+}
+
+impl Serialize for StateSchema {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeMap;
+        let len = if self.num_byte_slice != 0 { 1 } else { 0 }
+            + if self.num_uint != 0 { 1 } else { 0 };
+        let mut state = serializer.serialize_map(Some(len))?;
+        if self.num_byte_slice != 0 {
+            state.serialize_entry("nbs", &self.num_byte_slice)?;
+        }
+        if self.num_uint != 0 {
+            state.serialize_entry("nui", &self.num_uint)?;
+        }
+        state.end()
+    }
+}
+
+impl Serialize for BoxReference {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeMap;
+        let len = if self.app_index != 0 { 1 } else { 0 } + if self.name.is_empty() { 0 } else { 1 };
+        let mut state = serializer.serialize_map(Some(len))?;
+        if self.app_index != 0 {
+            state.serialize_entry("i", &self.app_index)?;
+        }
+        if !self.name.is_empty() {
+            state.serialize_entry("n", serde_bytes::Bytes::new(&self.name))?;
+        }
         state.end()
     }
 }
@@ -92,6 +403,15 @@ impl Serialize for HashDigest {
     }
 }
 
+impl Serialize for Lease {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.0[..])
+    }
+}
+
 impl Serialize for VotePK {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
@@ -128,6 +448,15 @@ impl<'de> Deserialize<'de> for HashDigest {
     }
 }
 
+impl<'de> Deserialize<'de> for Lease {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Lease(deserializer.deserialize_bytes(U8_32Visitor)?))
+    }
+}
+
 impl<'de> Deserialize<'de> for VotePK {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -172,6 +501,51 @@ impl Serialize for MultisigSignature {
     }
 }
 
+impl Serialize for MultisigAddress {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        // Addresses rather than raw keys, so that the json encoding can be read and compared too
+        use serde::ser::SerializeMap;
+        let addresses: Vec<String> = self
+            .public_keys
+            .iter()
+            .map(|key| Address(key.0).encode_string())
+            .collect();
+        let mut state = serializer.serialize_map(Some(3))?;
+        state.serialize_entry("addresses", &addresses)?;
+        state.serialize_entry("threshold", &self.threshold)?;
+        state.serialize_entry("version", &self.version)?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct MultisigDescriptor {
+    addresses: Vec<String>,
+    threshold: u8,
+    version: u8,
+}
+
+impl<'de> Deserialize<'de> for MultisigAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let descriptor = MultisigDescriptor::deserialize(deserializer)?;
+        let addresses = descriptor
+            .addresses
+            .iter()
+            .map(|address| Address::from_string(address))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(D::Error::custom)?;
+        MultisigAddress::new(descriptor.version, descriptor.threshold, &addresses)
+            .map_err(D::Error::custom)
+    }
+}
+
 impl Serialize for MultisigSubsig {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
@@ -188,6 +562,36 @@ impl Serialize for MultisigSubsig {
     }
 }
 
+impl Serialize for LogicSig {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeMap;
+        let len = 1
+            + if self.args.is_empty() { 0 } else { 1 }
+            + if self.msig.is_some() { 1 } else { 0 }
+            + if self.sig.is_some() { 1 } else { 0 };
+        let mut state = serializer.serialize_map(Some(len))?;
+        if !self.args.is_empty() {
+            let args: Vec<_> = self
+                .args
+                .iter()
+                .map(|arg| serde_bytes::Bytes::new(arg))
+                .collect();
+            state.serialize_entry("arg", &args)?;
+        }
+        state.serialize_entry("l", serde_bytes::Bytes::new(&self.logic))?;
+        if let Some(msig) = &self.msig {
+            state.serialize_entry("msig", msig)?;
+        }
+        if let Some(sig) = &self.sig {
+            state.serialize_entry("sig", sig)?;
+        }
+        state.end()
+    }
+}
+
 impl Serialize for Address {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
@@ -290,9 +694,14 @@ pub fn deserialize_bytes32<'de, D>(deserializer: D) -> Result<[u8; 32], D::Error
 where
     D: Deserializer<'de>,
 {
+    use serde::de::Error;
     let s = <&str>::deserialize(deserializer)?;
+    let bytes = BASE64.decode(s.as_bytes()).map_err(D::Error::custom)?;
+    if bytes.len() != 32 {
+        return Err(D::Error::invalid_length(bytes.len(), &"32 bytes"));
+    }
     let mut decoded = [0; 32];
-    decoded.copy_from_slice(&BASE64.decode(s.as_bytes()).unwrap());
+    decoded.copy_from_slice(&bytes);
     Ok(decoded)
 }
 
@@ -302,8 +711,11 @@ where
 {
     use serde::de::Error;
     let s = <&str>::deserialize(deserializer)?;
-    let mut decoded = [0; 64];
     let bytes = BASE64.decode(s.as_bytes()).map_err(D::Error::custom)?;
+    if bytes.len() != 64 {
+        return Err(D::Error::invalid_length(bytes.len(), &"64 bytes"));
+    }
+    let mut decoded = [0; 64];
     decoded.copy_from_slice(&bytes);
     Ok(decoded)
 }
@@ -312,8 +724,24 @@ pub fn deserialize_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
 where
     D: Deserializer<'de>,
 {
+    use serde::de::Error;
     let s = <&str>::deserialize(deserializer)?;
-    Ok(BASE64.decode(s.as_bytes()).unwrap())
+    BASE64.decode(s.as_bytes()).map_err(D::Error::custom)
+}
+
+pub fn deserialize_byte_vecs<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let byte_bufs = Vec::<serde_bytes::ByteBuf>::deserialize(deserializer)?;
+    Ok(byte_bufs.into_iter().map(serde_bytes::ByteBuf::into_vec).collect())
+}
+
+pub fn serialize_hash<S>(hash: &HashDigest, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&BASE64.encode(&hash.0))
 }
 
 pub fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
@@ -329,7 +757,12 @@ impl Error for crate::Error {
             crate::Error::Reqwest(e) => Some(e),
             crate::Error::Encode(e) => Some(e),
             crate::Error::Json(e) => Some(e),
-            crate::Error::Api(_) => None,
+            crate::Error::Http(_) => None,
+            crate::Error::Decoding(_) => None,
+            crate::Error::Validation(_) => None,
+            crate::Error::Verification(_) => None,
+            crate::Error::TransactionRejected { .. } => None,
+            crate::Error::ConfirmationTimeout { .. } => None,
         }
     }
 }
@@ -340,6 +773,12 @@ impl From<rmp_serde::encode::Error> for crate::Error {
     }
 }
 
+impl From<rmp_serde::decode::Error> for crate::Error {
+    fn from(err: rmp_serde::decode::Error) -> Self {
+        crate::Error::Decoding(crate::DecodingError::Msgpack(err.to_string()))
+    }
+}
+
 impl From<reqwest::Error> for crate::Error {
     fn from(err: reqwest::Error) -> Self {
         crate::Error::Reqwest(err)
@@ -358,11 +797,69 @@ impl Display for crate::Error {
             crate::Error::Reqwest(e) => Display::fmt(e, f),
             crate::Error::Encode(e) => Display::fmt(e, f),
             crate::Error::Json(e) => Display::fmt(e, f),
-            crate::Error::Api(e) => Display::fmt(e, f),
+            crate::Error::Http(e) => Display::fmt(e, f),
+            crate::Error::Decoding(e) => Display::fmt(e, f),
+            crate::Error::Validation(e) => Display::fmt(e, f),
+            crate::Error::Verification(e) => Display::fmt(e, f),
+            crate::Error::TransactionRejected {
+                transaction_id,
+                pool_error,
+            } => write!(
+                f,
+                "Transaction {} was rejected: {}",
+                transaction_id, pool_error
+            ),
+            crate::Error::ConfirmationTimeout {
+                transaction_id,
+                rounds,
+            } => write!(
+                f,
+                "Transaction {} wasn't confirmed after {} rounds",
+                transaction_id, rounds
+            ),
+        }
+    }
+}
+
+impl From<crate::HttpError> for crate::Error {
+    fn from(err: crate::HttpError) -> Self {
+        crate::Error::Http(err)
+    }
+}
+
+impl From<crate::DecodingError> for crate::Error {
+    fn from(err: crate::DecodingError) -> Self {
+        crate::Error::Decoding(err)
+    }
+}
+
+impl Display for crate::HttpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP status {}: {}", self.status, self.message)
+    }
+}
+
+impl Error for crate::HttpError {}
+
+impl Display for crate::DecodingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            crate::DecodingError::Encoding(e) => write!(f, "Invalid encoding: {}", e),
+            crate::DecodingError::Length { expected, actual } => {
+                write!(f, "Wrong length, expected {} got {}", expected, actual)
+            }
+            crate::DecodingError::Checksum => f.write_str("Checksum failed to validate"),
+            crate::DecodingError::UnknownWord(word) => {
+                write!(f, "{:?} is not in the mnemonic word list", word)
+            }
+            crate::DecodingError::Multisig(e) => Display::fmt(e, f),
+            crate::DecodingError::Msgpack(e) => write!(f, "Invalid msgpack: {}", e),
         }
     }
 }
 
+impl Error for crate::DecodingError {}
+
 impl Display for MicroAlgos {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         Display::fmt(&self.0, f)
@@ -375,6 +872,61 @@ impl Display for Round {
     }
 }
 
+impl Display for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encode_string())
+    }
+}
+
+impl FromStr for Address {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Address::from_string(s)
+    }
+}
+
+impl Display for MultisigAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "msig:{}:{}:", self.version, self.threshold)?;
+        for (i, key) in self.public_keys.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            Display::fmt(&Address(key.0), f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MultisigAddress {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            DecodingError::Multisig(
+                "Multisig descriptors look like msig:<version>:<threshold>:<addresses>".to_string(),
+            )
+        };
+        let mut parts = s.splitn(4, ':');
+        if parts.next() != Some("msig") {
+            return Err(invalid().into());
+        }
+        let version = parts.next().and_then(|version| version.parse().ok());
+        let threshold = parts.next().and_then(|threshold| threshold.parse().ok());
+        let addresses = parts.next().ok_or_else(invalid)?;
+        let addresses = addresses
+            .split(',')
+            .map(Address::from_string)
+            .collect::<Result<Vec<_>, _>>()?;
+        MultisigAddress::new(
+            version.ok_or_else(invalid)?,
+            threshold.ok_or_else(invalid)?,
+            &addresses,
+        )
+    }
+}
+
 impl Debug for ExportKeyResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ExportKeyResponse")
@@ -1,31 +1,77 @@
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use cucumber::{Steps, StepsBuilder};
 use data_encoding::BASE64;
+#[cfg(feature = "async")]
+use futures::StreamExt;
+use serde::Serialize;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 
 use algo_rust_sdk::account::Account;
 use algo_rust_sdk::algod::models::NodeStatus;
 use algo_rust_sdk::auction::{Bid, SignedBid};
-use algo_rust_sdk::crypto::{Address, MultisigAddress};
+use algo_rust_sdk::crypto::{Address, LogicSig, MultisigAddress, Signature};
+use algo_rust_sdk::message::{Message, SignedMessage};
+use algo_rust_sdk::multisig::PartiallySignedTransaction;
 use algo_rust_sdk::transaction::{
-    BaseTransaction, KeyRegistration, Payment, SignedTransaction, Transaction, TransactionType,
+    ApplicationCall, AssetConfig, AssetFreeze, AssetParams, AssetTransfer, BaseTransaction,
+    BoxReference, KeyRegistration, OnCompletion, Payment, SignedTransaction, SignerKind,
+    StateSchema, Transaction, TransactionType,
+};
+use algo_rust_sdk::transaction;
+use algo_rust_sdk::transaction::builder::{
+    ApplicationCallBuilder, AssetConfigBuilder, AssetFreezeBuilder, AssetTransferBuilder,
+    GroupFeePlanner, KeyRegBuilder, PaymentBuilder, SuggestedParams, TransactionBuilder,
 };
 use algo_rust_sdk::AlgodClient;
+use algo_rust_sdk::AlgodClientV2;
+use algo_rust_sdk::indexer::queries::{
+    AccountQuery, AddressRole, ApplicationQuery, AssetQuery, BalanceQuery, SigType,
+    TransactionQuery, TxType,
+};
+use algo_rust_sdk::IndexerClient;
+#[cfg(feature = "async")]
+use algo_rust_sdk::{AsyncAlgodClient, AsyncAlgodClientV2, AsyncIndexerClient, AsyncKmdClient};
 use algo_rust_sdk::KmdClient;
+use algo_rust_sdk::signer::{KmdSigner, MultisigSigner, TransactionSigner};
+use algo_rust_sdk::transport::{HttpRequest, HttpResponse, HttpTransport};
 use algo_rust_sdk::{
-    mnemonic, Ed25519PublicKey, HashDigest, MasterDerivationKey, MicroAlgos, Round, VotePK, VRFPK,
+    mnemonic, Ed25519PublicKey, HashDigest, Lease, MasterDerivationKey, MicroAlgos, Round, VotePK,
+    VRFPK,
 };
 
 #[derive(Default)]
 pub struct World {
     algod_client: Option<AlgodClient>,
+    algod_v2_client: Option<AlgodClientV2>,
+    indexer_client: Option<IndexerClient>,
+    #[cfg(feature = "async")]
+    async_algod_client: Option<AsyncAlgodClient>,
+    #[cfg(feature = "async")]
+    async_algod_v2_client: Option<AsyncAlgodClientV2>,
+    #[cfg(feature = "async")]
+    async_indexer_client: Option<AsyncIndexerClient>,
+    #[cfg(feature = "async")]
+    async_kmd_client: Option<AsyncKmdClient>,
+    mock_requests: Option<Receiver<String>>,
+    transport_requests: Arc<Mutex<Vec<HttpRequest>>>,
+    transport_response: Option<HttpResponse>,
+    transport_headers: HeaderMap,
+    error: Option<algo_rust_sdk::Error>,
+    suggested_params: Option<SuggestedParams>,
+    response: Option<serde_json::Value>,
     kmd_client: Option<KmdClient>,
     versions: Vec<String>,
     status: Option<NodeStatus>,
     status_after: Option<NodeStatus>,
     transaction_id: Option<String>,
+    transaction_ids: Vec<String>,
     last_round: Option<Round>,
     params_fee: Option<MicroAlgos>,
     wallet_name: Option<String>,
@@ -46,6 +92,7 @@ pub struct World {
     note: Vec<u8>,
     genesis_hash: Option<HashDigest>,
     genesis_id: String,
+    lease: Option<Lease>,
     vote_pk: Option<VotePK>,
     vrf_pk: Option<VRFPK>,
     vote_first: Option<Round>,
@@ -54,7 +101,16 @@ pub struct World {
     receiver: Option<Address>,
     close: Option<Address>,
     amount: Option<MicroAlgos>,
+    asset_id: Option<u64>,
+    asset_amount: Option<u64>,
+    asset_sender: Option<Address>,
+    asset_params: Option<AssetParams>,
+    frozen: Option<bool>,
+    application_call: Option<ApplicationCall>,
+    group: Vec<Transaction>,
+    group_id: Option<HashDigest>,
     multisig: Option<MultisigAddress>,
+    logic_sig: Option<LogicSig>,
     transaction: Option<Transaction>,
     signed_transaction: Option<SignedTransaction>,
     signed_transaction_bytes: Vec<u8>,
@@ -63,6 +119,10 @@ pub struct World {
     bid: Option<Bid>,
     old_bid: Option<SignedBid>,
     signed_bid: Option<SignedBid>,
+    bytes_signature: Option<Signature>,
+    signed_message: Option<SignedMessage<serde_json::Value>>,
+    signed_challenge: Option<SignedMessage<LoginChallenge>>,
+    partially_signed: Option<PartiallySignedTransaction>,
     err: bool,
     num: String,
 }
@@ -89,11 +149,7 @@ pub fn steps() -> Steps<World> {
                 f.read_line(&mut address).unwrap();
                 address
             };
-            let version:String = {
-                let v1 = "v1".to_string();
-                version
-            };
-            world.algod_client = Some(AlgodClient::new(&address, &token,&version));
+            world.algod_client = Some(AlgodClient::new(&address, &token));
         })
         .given("a kmd client", |world: &mut World, _step| {
             let home = dirs::home_dir().expect("Couldn't get home dir");
@@ -150,7 +206,7 @@ pub fn steps() -> Steps<World> {
         })
         .then("I get transactions by address and date", |world: &mut World, _step| {
             let algod_client = world.algod_client.as_ref().unwrap();
-            let _ = algod_client.transactions(&world.accounts[0], None, None, Some(chrono::Local::today().naive_local().to_string()), Some(chrono::Local::today().naive_local().to_string()), Some(10)).unwrap();
+            let _ = algod_client.transactions(&world.accounts[0], None, None, Some(chrono::Local::now().date_naive().to_string()), Some(chrono::Local::now().date_naive().to_string()), Some(10)).unwrap();
         })
         .when_regex(r"^I get recent transactions, limited by (\d+) transactions$", |world: &mut World, strings, _step| {
             let algod_client = world.algod_client.as_ref().unwrap();
@@ -202,6 +258,7 @@ pub fn steps() -> Steps<World> {
                 note: world.note.clone(),
                 genesis_id: params.genesis_id,
                 genesis_hash: params.genesis_hash,
+                lease: None,
             };
             let payment = Payment {
                 amount,
@@ -234,6 +291,7 @@ pub fn steps() -> Steps<World> {
                 note: world.note.clone(),
                 genesis_id: params.genesis_id,
                 genesis_hash: params.genesis_hash,
+                lease: None,
             };
             let payment = Payment {
                 amount,
@@ -486,7 +544,7 @@ pub fn steps() -> Steps<World> {
             world.account = Some(account);
         })
         .when("I decode the address", |world: &mut World, _step| {
-            world.public_key = Some(Address::from_string(&world.address.as_ref().unwrap()).unwrap());
+            world.public_key = Some(Address::from_string(world.address.as_ref().unwrap()).unwrap());
             world.old_address = world.address.clone();
         })
         .when("I encode the address", |world: &mut World, _step| {
@@ -531,6 +589,9 @@ pub fn steps() -> Steps<World> {
                 world.note = BASE64.decode(strings[9].as_bytes()).unwrap();
             }
         })
+        .given_regex(r#"lease "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.lease = Some(Lease::new(&BASE64.decode(strings[1].as_bytes()).unwrap()).unwrap());
+        })
         .given_regex(r#"key registration transaction parameters (\d+) (\d+) (\d+) "([^"]*)" "([^"]*)" "([^"]*)" (\d+) (\d+) (\d+) "([^"]*)" "([^"]*)"#, |world: &mut World, strings, _step| {
             world.fee = Some(MicroAlgos(strings[1].parse().unwrap()));
             world.first_valid = Some(Round(strings[2].parse().unwrap()));
@@ -556,6 +617,138 @@ pub fn steps() -> Steps<World> {
                 world.note = BASE64.decode(strings[11].as_bytes()).unwrap();
             }
         })
+        .given_regex(r#"asset transfer transaction parameters (\d+) (\d+) (\d+) "([^"]*)" (\d+) "([^"]*)" "([^"]*)" "([^"]*)" (\d+) "([^"]*)" "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.fee = Some(MicroAlgos(strings[1].parse().unwrap()));
+            world.first_valid = Some(Round(strings[2].parse().unwrap()));
+            world.last_valid = Some(Round(strings[3].parse().unwrap()));
+            let mut genesis_hash = [0; 32];
+            genesis_hash.copy_from_slice(&BASE64.decode(strings[4].as_bytes()).unwrap());
+            world.genesis_hash = Some(HashDigest(genesis_hash));
+            world.asset_id = Some(strings[5].parse().unwrap());
+            world.receiver = Some(Address::from_string(&strings[6]).unwrap());
+            if strings[7] != "none" {
+                world.asset_sender = Some(Address::from_string(&strings[7]).unwrap());
+            }
+            if strings[8] != "none" {
+                world.close = Some(Address::from_string(&strings[8]).unwrap());
+            }
+            world.asset_amount = Some(strings[9].parse().unwrap());
+            if strings[10] != "none" {
+                world.genesis_id = strings[10].clone();
+            }
+            if strings[11] != "none" {
+                world.note = BASE64.decode(strings[11].as_bytes()).unwrap();
+            }
+        })
+        .given_regex(r#"asset config transaction parameters (\d+) (\d+) (\d+) "([^"]*)" (\d+) (\d+) (\d+) "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.fee = Some(MicroAlgos(strings[1].parse().unwrap()));
+            world.first_valid = Some(Round(strings[2].parse().unwrap()));
+            world.last_valid = Some(Round(strings[3].parse().unwrap()));
+            let mut genesis_hash = [0; 32];
+            genesis_hash.copy_from_slice(&BASE64.decode(strings[4].as_bytes()).unwrap());
+            world.genesis_hash = Some(HashDigest(genesis_hash));
+            world.asset_id = Some(strings[5].parse().unwrap());
+            let optional_string = |s: &String| if s != "none" { s.clone() } else { String::new() };
+            let optional_address = |s: &String| if s != "none" { Some(Address::from_string(s).unwrap()) } else { None };
+            let metadata_hash = if strings[12] != "none" {
+                let mut metadata_hash = [0; 32];
+                metadata_hash.copy_from_slice(&BASE64.decode(strings[12].as_bytes()).unwrap());
+                Some(HashDigest(metadata_hash))
+            } else {
+                None
+            };
+            let params = AssetParams {
+                total: strings[6].parse().unwrap(),
+                decimals: strings[7].parse().unwrap(),
+                default_frozen: strings[8].parse().unwrap(),
+                unit_name: optional_string(&strings[9]),
+                asset_name: optional_string(&strings[10]),
+                url: optional_string(&strings[11]),
+                metadata_hash,
+                manager: optional_address(&strings[13]),
+                reserve: optional_address(&strings[14]),
+                freeze: optional_address(&strings[15]),
+                clawback: optional_address(&strings[16]),
+            };
+            // Leaving every parameter unset destroys the asset
+            world.asset_params = Some(params).filter(|params| !params.is_empty());
+            if strings[17] != "none" {
+                world.genesis_id = strings[17].clone();
+            }
+            if strings[18] != "none" {
+                world.note = BASE64.decode(strings[18].as_bytes()).unwrap();
+            }
+        })
+        .given_regex(r#"asset freeze transaction parameters (\d+) (\d+) (\d+) "([^"]*)" "([^"]*)" (\d+) "([^"]*)" "([^"]*)" "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.fee = Some(MicroAlgos(strings[1].parse().unwrap()));
+            world.first_valid = Some(Round(strings[2].parse().unwrap()));
+            world.last_valid = Some(Round(strings[3].parse().unwrap()));
+            let mut genesis_hash = [0; 32];
+            genesis_hash.copy_from_slice(&BASE64.decode(strings[4].as_bytes()).unwrap());
+            world.genesis_hash = Some(HashDigest(genesis_hash));
+            world.receiver = Some(Address::from_string(&strings[5]).unwrap());
+            world.asset_id = Some(strings[6].parse().unwrap());
+            world.frozen = Some(strings[7].parse().unwrap());
+            if strings[8] != "none" {
+                world.genesis_id = strings[8].clone();
+            }
+            if strings[9] != "none" {
+                world.note = BASE64.decode(strings[9].as_bytes()).unwrap();
+            }
+        })
+        .given_regex(r#"application call transaction parameters (\d+) (\d+) (\d+) "([^"]*)" (\d+) (\d+) "([^"]*)" "([^"]*)" (\d+) (\d+) (\d+) (\d+) (\d+) "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)" "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.fee = Some(MicroAlgos(strings[1].parse().unwrap()));
+            world.first_valid = Some(Round(strings[2].parse().unwrap()));
+            world.last_valid = Some(Round(strings[3].parse().unwrap()));
+            let mut genesis_hash = [0; 32];
+            genesis_hash.copy_from_slice(&BASE64.decode(strings[4].as_bytes()).unwrap());
+            world.genesis_hash = Some(HashDigest(genesis_hash));
+            let list = |s: &String| -> Vec<String> {
+                if s != "none" { s.split(',').map(str::to_string).collect() } else { Vec::new() }
+            };
+            let on_complete = match strings[6].as_str() {
+                "0" => OnCompletion::NoOp,
+                "1" => OnCompletion::OptIn,
+                "2" => OnCompletion::CloseOut,
+                "3" => OnCompletion::ClearState,
+                "4" => OnCompletion::UpdateApplication,
+                "5" => OnCompletion::DeleteApplication,
+                other => panic!("Unknown on completion {}", other),
+            };
+            let program = |s: &String| if s != "none" { BASE64.decode(s.as_bytes()).unwrap() } else { Vec::new() };
+            world.application_call = Some(ApplicationCall {
+                app_id: strings[5].parse().unwrap(),
+                on_complete,
+                approval_program: program(&strings[7]),
+                clear_state_program: program(&strings[8]),
+                global_state_schema: StateSchema {
+                    num_uint: strings[9].parse().unwrap(),
+                    num_byte_slice: strings[10].parse().unwrap(),
+                },
+                local_state_schema: StateSchema {
+                    num_uint: strings[11].parse().unwrap(),
+                    num_byte_slice: strings[12].parse().unwrap(),
+                },
+                extra_pages: strings[13].parse().unwrap(),
+                app_arguments: list(&strings[14]).iter().map(|arg| BASE64.decode(arg.as_bytes()).unwrap()).collect(),
+                accounts: list(&strings[15]).iter().map(|address| Address::from_string(address).unwrap()).collect(),
+                foreign_apps: list(&strings[16]).iter().map(|id| id.parse().unwrap()).collect(),
+                foreign_assets: list(&strings[17]).iter().map(|id| id.parse().unwrap()).collect(),
+                boxes: list(&strings[18]).iter().map(|reference| {
+                    let (index, name) = reference.split_once(':').unwrap();
+                    BoxReference {
+                        app_index: index.parse().unwrap(),
+                        name: BASE64.decode(name.as_bytes()).unwrap(),
+                    }
+                }).collect(),
+            });
+            if strings[19] != "none" {
+                world.genesis_id = strings[19].clone();
+            }
+            if strings[20] != "none" {
+                world.note = BASE64.decode(strings[20].as_bytes()).unwrap();
+            }
+        })
         .when("I create the payment transaction", |world: &mut World, _step| {
             let base = BaseTransaction {
                 sender: world.public_key.expect("No public key"),
@@ -564,6 +757,7 @@ pub fn steps() -> Steps<World> {
                 note: world.note.clone(),
                 genesis_id: world.genesis_id.clone(),
                 genesis_hash: world.genesis_hash.expect("No genesis hash"),
+                lease: world.lease,
             };
             let payment = Payment {
                 amount: world.amount.expect("No amount"),
@@ -584,6 +778,7 @@ pub fn steps() -> Steps<World> {
                 note: world.note.clone(),
                 genesis_id: world.genesis_id.clone(),
                 genesis_hash: world.genesis_hash.expect("No genesis hash"),
+                lease: world.lease,
             };
             let payment = Payment {
                 amount: world.amount.expect("No amount"),
@@ -604,6 +799,7 @@ pub fn steps() -> Steps<World> {
                 note: world.note.clone(),
                 genesis_id: world.genesis_id.clone(),
                 genesis_hash: world.genesis_hash.expect("No genesis hash"),
+                lease: world.lease,
             };
             let payment = Payment {
                 amount: world.amount.expect("No amount"),
@@ -624,6 +820,7 @@ pub fn steps() -> Steps<World> {
                 note: world.note.clone(),
                 genesis_id: world.genesis_id.clone(),
                 genesis_hash: world.genesis_hash.expect("No genesis hash"),
+                lease: world.lease,
             };
             let key_registration = KeyRegistration {
                 vote_pk: world.vote_pk.expect("No vote public key"),
@@ -638,6 +835,291 @@ pub fn steps() -> Steps<World> {
                 TransactionType::KeyRegistration(key_registration),
             ).unwrap());
         })
+        .when("I create the asset transfer transaction", |world: &mut World, _step| {
+            let base = BaseTransaction {
+                sender: world.public_key.expect("No public key"),
+                first_valid: world.first_valid.expect("No first valid"),
+                last_valid: world.last_valid.expect("No last valid"),
+                note: world.note.clone(),
+                genesis_id: world.genesis_id.clone(),
+                genesis_hash: world.genesis_hash.expect("No genesis hash"),
+                lease: world.lease,
+            };
+            let asset_transfer = AssetTransfer {
+                asset_id: world.asset_id.expect("No asset id"),
+                amount: world.asset_amount.expect("No asset amount"),
+                receiver: world.receiver.expect("No receiver"),
+                asset_sender: world.asset_sender,
+                close_to: world.close,
+            };
+            world.transaction = Some(Transaction::new(
+                base,
+                world.fee.expect("No fee"),
+                TransactionType::AssetTransfer(asset_transfer),
+            ).unwrap());
+        })
+        .when("I create the asset config transaction", |world: &mut World, _step| {
+            let base = BaseTransaction {
+                sender: world.public_key.expect("No public key"),
+                first_valid: world.first_valid.expect("No first valid"),
+                last_valid: world.last_valid.expect("No last valid"),
+                note: world.note.clone(),
+                genesis_id: world.genesis_id.clone(),
+                genesis_hash: world.genesis_hash.expect("No genesis hash"),
+                lease: world.lease,
+            };
+            let asset_config = AssetConfig {
+                asset_id: world.asset_id.expect("No asset id"),
+                params: world.asset_params.clone(),
+            };
+            world.transaction = Some(Transaction::new(
+                base,
+                world.fee.expect("No fee"),
+                TransactionType::AssetConfig(asset_config),
+            ).unwrap());
+        })
+        .when("I create the asset freeze transaction", |world: &mut World, _step| {
+            let base = BaseTransaction {
+                sender: world.public_key.expect("No public key"),
+                first_valid: world.first_valid.expect("No first valid"),
+                last_valid: world.last_valid.expect("No last valid"),
+                note: world.note.clone(),
+                genesis_id: world.genesis_id.clone(),
+                genesis_hash: world.genesis_hash.expect("No genesis hash"),
+                lease: world.lease,
+            };
+            let asset_freeze = AssetFreeze {
+                freeze_account: world.receiver.expect("No freeze account"),
+                asset_id: world.asset_id.expect("No asset id"),
+                frozen: world.frozen.expect("No frozen flag"),
+            };
+            world.transaction = Some(Transaction::new(
+                base,
+                world.fee.expect("No fee"),
+                TransactionType::AssetFreeze(asset_freeze),
+            ).unwrap());
+        })
+        .when("I create the application call transaction", |world: &mut World, _step| {
+            let base = BaseTransaction {
+                sender: world.public_key.expect("No public key"),
+                first_valid: world.first_valid.expect("No first valid"),
+                last_valid: world.last_valid.expect("No last valid"),
+                note: world.note.clone(),
+                genesis_id: world.genesis_id.clone(),
+                genesis_hash: world.genesis_hash.expect("No genesis hash"),
+                lease: world.lease,
+            };
+            world.transaction = Some(Transaction::new(
+                base,
+                world.fee.expect("No fee"),
+                TransactionType::ApplicationCall(world.application_call.clone().expect("No application call")),
+            ).unwrap());
+        })
+        .then_regex(r#"building the (payment|flat fee payment|key registration|asset transfer|asset config|asset freeze|application call) transaction from suggested params should give the same transaction( without its lease)?"#, |world: &mut World, strings, _step| {
+            let params = SuggestedParams {
+                fee: world.fee.expect("No fee"),
+                flat_fee: strings[1] == "flat fee payment",
+                min_fee: MicroAlgos(1000),
+                first_valid: world.first_valid.expect("No first valid"),
+                last_valid: world.last_valid.expect("No last valid"),
+                genesis_id: world.genesis_id.clone(),
+                genesis_hash: world.genesis_hash.expect("No genesis hash"),
+            };
+            let sender = world.public_key.expect("No public key");
+            let transaction = match strings[1].as_str() {
+                "payment" | "flat fee payment" => {
+                    let mut builder = PaymentBuilder::new(sender, &params)
+                        .receiver(world.receiver.expect("No receiver"))
+                        .amount(world.amount.expect("No amount"));
+                    if let Some(close) = world.close {
+                        builder = builder.close_remainder_to(close);
+                    }
+                    with_note_and_lease(builder, world).build()
+                }
+                "key registration" => {
+                    let builder = KeyRegBuilder::new(sender, &params)
+                        .vote_pk(world.vote_pk.expect("No vote public key"))
+                        .selection_pk(world.vrf_pk.expect("No VRFPK"))
+                        .vote_rounds(world.vote_first.expect("No vote first"), world.vote_last.expect("No vote last"))
+                        .vote_key_dilution(world.vote_key_dilution.expect("No vote key dilution"));
+                    with_note_and_lease(builder, world).build()
+                }
+                "asset transfer" => {
+                    let mut builder = AssetTransferBuilder::new(sender, &params)
+                        .asset_id(world.asset_id.expect("No asset id"))
+                        .receiver(world.receiver.expect("No receiver"))
+                        .amount(world.asset_amount.expect("No asset amount"));
+                    if let Some(asset_sender) = world.asset_sender {
+                        builder = builder.asset_sender(asset_sender);
+                    }
+                    if let Some(close) = world.close {
+                        builder = builder.close_to(close);
+                    }
+                    with_note_and_lease(builder, world).build()
+                }
+                "asset config" => {
+                    let mut builder = AssetConfigBuilder::new(sender, &params).asset_id(world.asset_id.expect("No asset id"));
+                    if let Some(asset_params) = world.asset_params.clone() {
+                        builder = builder.params(asset_params);
+                    }
+                    with_note_and_lease(builder, world).build()
+                }
+                "asset freeze" => {
+                    let builder = AssetFreezeBuilder::new(sender, &params)
+                        .asset_id(world.asset_id.expect("No asset id"))
+                        .freeze_account(world.receiver.expect("No freeze account"))
+                        .frozen(world.frozen.expect("No frozen flag"));
+                    with_note_and_lease(builder, world).build()
+                }
+                _ => {
+                    let call = world.application_call.clone().expect("No application call");
+                    let builder = ApplicationCallBuilder::new(sender, &params)
+                        .app_id(call.app_id)
+                        .on_complete(call.on_complete)
+                        .programs(call.approval_program, call.clear_state_program)
+                        .state_schemas(call.global_state_schema, call.local_state_schema)
+                        .extra_pages(call.extra_pages)
+                        .app_arguments(call.app_arguments)
+                        .accounts(call.accounts)
+                        .foreign_apps(call.foreign_apps)
+                        .foreign_assets(call.foreign_assets)
+                        .boxes(call.boxes);
+                    with_note_and_lease(builder, world).build()
+                }
+            };
+            let mut expected = world.transaction.clone().unwrap();
+            if !strings[2].is_empty() {
+                expected.lease = None;
+            }
+            assert_eq!(transaction.unwrap(), expected);
+        })
+        .given_regex(r#"suggested params from the fixture "([^"]*)""#, |world: &mut World, strings, _step| {
+            let params: algo_rust_sdk::algod_v2::models::TransactionParams = serde_json::from_str(&std::fs::read_to_string(fixture_path(&strings[1])).unwrap()).unwrap();
+            world.suggested_params = Some(params.into());
+        })
+        .when_regex(r#"I build a payment of (\d+) microalgos from "([^"]*)" to "([^"]*)"(?: valid from round (\d+) to round (\d+))?"#, |world: &mut World, strings, _step| {
+            let params = world.suggested_params.as_ref().expect("No suggested params");
+            let mut builder = PaymentBuilder::new(Address::from_string(&strings[2]).unwrap(), params)
+                .receiver(Address::from_string(&strings[3]).unwrap())
+                .amount(MicroAlgos(strings[1].parse().unwrap()));
+            if !strings[4].is_empty() {
+                builder = builder.first_valid(Round(strings[4].parse().unwrap())).last_valid(Round(strings[5].parse().unwrap()));
+            }
+            match builder.build() {
+                Ok(transaction) => world.transaction = Some(transaction),
+                Err(error) => world.error = Some(error),
+            }
+        })
+        .when_regex(r#"I build a payment of (\d+) microalgos from "([^"]*)" to "([^"]*)" with a fee of (\d+) microalgos per byte signed by "([^"]*)""#, |world: &mut World, strings, _step| {
+            let params = world.suggested_params.as_ref().expect("No suggested params");
+            let signer = match strings[5].split_once(' ') {
+                Some(("multisig", multisig)) => {
+                    let mut fields = multisig.split(' ');
+                    let version = fields.next().unwrap().parse().unwrap();
+                    let threshold = fields.next().unwrap().parse().unwrap();
+                    let addresses: Vec<Address> = fields.map(|address| Address::from_string(address).unwrap()).collect();
+                    SignerKind::Multisig(MultisigAddress::new(version, threshold, &addresses).unwrap())
+                }
+                Some(("single", address)) => SignerKind::Single(Some(Address::from_string(address).unwrap())),
+                Some(("lsig", program)) => SignerKind::LogicSig(LogicSig::new(BASE64.decode(program.as_bytes()).unwrap(), vec![])),
+                _ => SignerKind::default(),
+            };
+            let transaction = PaymentBuilder::new(Address::from_string(&strings[2]).unwrap(), params)
+                .receiver(Address::from_string(&strings[3]).unwrap())
+                .amount(MicroAlgos(strings[1].parse().unwrap()))
+                .fee_per_byte(MicroAlgos(strings[4].parse().unwrap()))
+                .signer(signer)
+                .build();
+            world.transaction = Some(transaction.unwrap());
+        })
+        .when_regex(r#"I build a (payment|key registration|asset transfer|asset config|asset freeze|application call) from "([^"]*)" with only its sender"#, |world: &mut World, strings, _step| {
+            let params = world.suggested_params.as_ref().expect("No suggested params");
+            let sender = Address::from_string(&strings[2]).unwrap();
+            let result = match strings[1].as_str() {
+                "payment" => PaymentBuilder::new(sender, params).build(),
+                "key registration" => KeyRegBuilder::new(sender, params).build(),
+                "asset transfer" => AssetTransferBuilder::new(sender, params).build(),
+                "asset config" => AssetConfigBuilder::new(sender, params).build(),
+                "asset freeze" => AssetFreezeBuilder::new(sender, params).build(),
+                _ => ApplicationCallBuilder::new(sender, params).build(),
+            };
+            world.error = result.err();
+        })
+        .then_regex(r#"the transaction should be valid from round (\d+) to round (\d+) with a fee of (\d+) microalgos"#, |world: &mut World, strings, _step| {
+            let transaction = world.transaction.as_ref().expect("No transaction");
+            assert_eq!(transaction.first_valid, Round(strings[1].parse().unwrap()));
+            assert_eq!(transaction.last_valid, Round(strings[2].parse().unwrap()));
+            assert_eq!(transaction.fee, MicroAlgos(strings[3].parse().unwrap()));
+        })
+        .when_regex(r#"I build a call to application (\d+) from "([^"]*)""#, |world: &mut World, strings, _step| {
+            let params = world.suggested_params.as_ref().expect("No suggested params");
+            let transaction = ApplicationCallBuilder::new(Address::from_string(&strings[2]).unwrap(), params)
+                .app_id(strings[1].parse().unwrap())
+                .build();
+            world.transaction = Some(transaction.unwrap());
+        })
+        .when_regex(r#"I pool the group fees into transaction (\d+) at (\d+) microalgos per byte with (\d+) inner transactions for transaction (\d+)"#, |world: &mut World, strings, _step| {
+            let mut params = world.suggested_params.clone().expect("No suggested params");
+            params.fee = MicroAlgos(strings[2].parse().unwrap());
+            let planner = GroupFeePlanner::new(&params, strings[1].parse().unwrap())
+                .inner_transactions(strings[4].parse().unwrap(), strings[3].parse().unwrap());
+            world.error = planner.apply(&mut world.group).err();
+        })
+        .then_regex(r#"the fees of the group should be (.*)$"#, |world: &mut World, strings, _step| {
+            let fees: Vec<String> = world.group.iter().map(|transaction| transaction.fee.to_string()).collect();
+            assert_eq!(fees.join(", "), strings[1]);
+        })
+        .when("I add the transaction to the group", |world: &mut World, _step| {
+            world.group.push(world.transaction.take().expect("No transaction"));
+        })
+        .when("I assign the group id", |world: &mut World, _step| {
+            world.group_id = Some(transaction::assign_group_id(&mut world.group).unwrap());
+        })
+        .when("I sign the transaction group with the private key", |world: &mut World, _step| {
+            world.signed_transactions = Some(world.account.as_ref().unwrap().sign_transactions(&world.group).expect("Failed to sign transactions"))
+        })
+        .then_regex(r#"the group id should equal the golden "([^"]*)""#, |world: &mut World, strings, _step| {
+            assert_eq!(BASE64.encode(&world.group_id.unwrap().0), strings[1])
+        })
+        .then_regex(r#"the signed transaction group should equal the golden "([^"]*)""#, |world: &mut World, strings, _step| {
+            let mut bytes = Vec::new();
+            for signed_transaction in world.signed_transactions.as_ref().unwrap() {
+                bytes.extend_from_slice(&rmp_serde::to_vec_named(signed_transaction).unwrap());
+            }
+            assert_eq!(BASE64.encode(&bytes), strings[1])
+        })
+        .when_regex(r#"I set the transaction sender to "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.transaction.as_mut().unwrap().sender = Address::from_string(&strings[1]).unwrap();
+        })
+        .when_regex(r#"I rekey the transaction to "([^"]*)""#, |world: &mut World, strings, _step| {
+            if strings[1] != "none" {
+                world.transaction.as_mut().unwrap().rekey_to = Some(Address::from_string(&strings[1]).unwrap());
+            }
+        })
+        .when("I set the transaction sender to the multisig address", |world: &mut World, _step| {
+            world.transaction.as_mut().unwrap().sender = world.multisig.as_ref().unwrap().address();
+        })
+        .given_regex(r#"logic sig program "([^"]*)" with arguments "([^"]*)""#, |world: &mut World, strings, _step| {
+            let program = BASE64.decode(strings[1].as_bytes()).unwrap();
+            let args = if strings[2] == "none" {
+                Vec::new()
+            } else {
+                strings[2].split(' ').map(|arg| BASE64.decode(arg.as_bytes()).unwrap()).collect()
+            };
+            world.logic_sig = Some(LogicSig::new(program, args));
+        })
+        .when("I sign the logic sig with the private key", |world: &mut World, _step| {
+            world.logic_sig = Some(world.account.as_ref().unwrap().sign_logic_sig(world.logic_sig.as_ref().unwrap()).unwrap());
+        })
+        .when("I sign the logic sig with the private key and the multisig address", |world: &mut World, _step| {
+            world.logic_sig = Some(world.account.as_ref().unwrap().sign_multisig_logic_sig(world.multisig.clone().unwrap(), world.logic_sig.as_ref().unwrap()).unwrap());
+        })
+        .when("I sign the transaction with the logic sig", |world: &mut World, _step| {
+            world.signed_transaction = Some(world.logic_sig.as_ref().unwrap().sign_transaction(world.transaction.as_ref().unwrap()).expect("Failed to sign transaction"))
+        })
+        .then_regex(r#"the logic sig address should equal "([^"]*)""#, |world: &mut World, strings, _step| {
+            assert_eq!(world.logic_sig.as_ref().unwrap().address().encode_string(), strings[1]);
+        })
         .when("I sign the transaction with the private key", |world: &mut World, _step| {
             world.signed_transaction = Some(world.account.as_ref().unwrap().sign_transaction(world.transaction.as_ref().unwrap()).expect("Failed to sign transaction"))
         })
@@ -648,10 +1130,76 @@ pub fn steps() -> Steps<World> {
             let bytes = rmp_serde::to_vec_named(world.signed_transaction.as_ref().unwrap()).unwrap();
             assert_eq!(BASE64.encode(&bytes), strings[1])
         })
+        .then_regex(r#"the estimated size for (a single signer|the multisig signer|the logic sig signer) should equal the length of the signed transaction"#, |world: &mut World, strings, _step| {
+            let signed_transaction = world.signed_transaction.as_ref().unwrap();
+            let signer = match strings[1].as_str() {
+                "a single signer" => SignerKind::Single(Some(world.account.as_ref().expect("No account").address())),
+                "the multisig signer" => {
+                    let msig = signed_transaction.multisig.as_ref().expect("No multisig signature");
+                    SignerKind::Multisig(MultisigAddress {
+                        version: msig.version,
+                        threshold: msig.threshold,
+                        public_keys: msig.subsigs.iter().map(|subsig| subsig.key).collect(),
+                    })
+                }
+                _ => SignerKind::LogicSig(signed_transaction.logic_sig.clone().expect("No logic sig")),
+            };
+            let size = signed_transaction.transaction.estimate_size(&signer).unwrap();
+            assert_eq!(size as usize, rmp_serde::to_vec_named(signed_transaction).unwrap().len());
+        })
+        .then("the signed transaction should verify", |world: &mut World, _step| {
+            world.signed_transaction.as_ref().unwrap().verify().unwrap();
+        })
+        .then_regex(r#"verifying the signed transaction should fail with "(.*)"$"#, |world: &mut World, strings, _step| {
+            let error = world.signed_transaction.as_ref().unwrap().verify().unwrap_err();
+            assert_eq!(error.to_string(), strings[1]);
+        })
+        .when("I change the fee of the signed transaction", |world: &mut World, _step| {
+            let signed_transaction = world.signed_transaction.as_mut().unwrap();
+            signed_transaction.transaction.fee = signed_transaction.transaction.fee + 1;
+        })
+        .when("I remove the signature of the signed transaction", |world: &mut World, _step| {
+            world.signed_transaction.as_mut().unwrap().sig = None;
+        })
+        .when_regex(r#"I set the auth address of the signed transaction to "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.signed_transaction.as_mut().unwrap().auth_address = Some(Address::from_string(&strings[1]).unwrap());
+        })
+        .then_regex(r#"signing it through the (account|multisig|logic sig) signer should give the same signed transaction"#, |world: &mut World, strings, _step| {
+            let signed_transaction = world.signed_transaction.as_ref().unwrap();
+            let transactions = [signed_transaction.transaction.clone()];
+            let signed = match strings[1].as_str() {
+                "account" => world.account.as_ref().unwrap().sign_group(&transactions, &[0]),
+                "multisig" => MultisigSigner::new(world.multisig.clone().unwrap(), vec![world.account.as_ref().unwrap()]).sign_group(&transactions, &[0]),
+                _ => world.logic_sig.as_ref().unwrap().sign_group(&transactions, &[0]),
+            };
+            assert_eq!(signed.unwrap(), vec![signed_transaction.clone()]);
+        })
         .then_regex(r#"the multisig transaction should equal the golden "([^"]*)""#, |world: &mut World, strings, _step| {
             let bytes = rmp_serde::to_vec_named(world.signed_transaction.as_ref().unwrap()).unwrap();
             assert_eq!(BASE64.encode(&bytes), strings[1])
         })
+        .then_regex(r#"^the signed transaction should decode to the same transaction( without its lease)?$"#, |world: &mut World, strings, _step| {
+            let signed_transaction = world.signed_transaction.as_ref().unwrap();
+            let bytes = signed_transaction.to_bytes().unwrap();
+            let decoded = SignedTransaction::from_bytes(&bytes).expect("Failed to decode");
+            let mut expected = signed_transaction.clone();
+            if !strings[1].is_empty() {
+                expected.transaction.lease = None;
+            }
+            assert_eq!(decoded, expected);
+            assert_eq!(decoded.to_bytes().unwrap(), bytes);
+        })
+        .then("the signed transaction group should decode to the same transactions", |world: &mut World, _step| {
+            for signed_transaction in world.signed_transactions.as_ref().unwrap() {
+                let bytes = signed_transaction.to_bytes().unwrap();
+                let decoded = SignedTransaction::from_bytes(&bytes).expect("Failed to decode");
+                assert_eq!(&decoded, signed_transaction);
+            }
+        })
+        .then_regex(r#"the transaction ID should equal "([^"]*)""#, |world: &mut World, strings, _step| {
+            assert_eq!(world.signed_transaction.as_ref().unwrap().transaction_id, strings[1]);
+            assert_eq!(world.signed_transaction.as_ref().unwrap().transaction.id().unwrap(), strings[1]);
+        })
         .given_regex(r#"multisig addresses "([^"]*)""#, |world: &mut World, strings, _step| {
             let addresses: Vec<Address> = strings[1].split(' ').map(|s| Address::from_string(s).unwrap()).collect();
             world.multisig = Some(MultisigAddress::new(1, 2, &addresses).unwrap());
@@ -660,6 +1208,68 @@ pub fn steps() -> Steps<World> {
             let msig_address = world.multisig.as_ref().unwrap().address();
             assert_eq!(msig_address.encode_string(), strings[1])
         })
+        .then_regex(r#"the multisig descriptor should be "([^"]*)""#, |world: &mut World, strings, _step| {
+            assert_eq!(world.multisig.as_ref().unwrap().to_string(), strings[1]);
+        })
+        .then_regex(r#"the json encoding of the multisig address should be (.*)$"#, |world: &mut World, strings, _step| {
+            assert_eq!(serde_json::to_string(world.multisig.as_ref().unwrap()).unwrap(), strings[1]);
+        })
+        .then("the multisig address should survive encoding to msgpack and json", |world: &mut World, _step| {
+            let multisig = world.multisig.as_ref().unwrap();
+            let msgpack: MultisigAddress = rmp_serde::from_slice(&rmp_serde::to_vec_named(multisig).unwrap()).unwrap();
+            let json: MultisigAddress = serde_json::from_str(&serde_json::to_string(multisig).unwrap()).unwrap();
+            assert_eq!(&msgpack, multisig);
+            assert_eq!(&json, multisig);
+        })
+        .when_regex(r#"I import the multisig descriptor "([^"]*)" expecting the address "([^"]*)""#, |world: &mut World, strings, _step| {
+            let expected: Address = strings[2].parse().unwrap();
+            let imported = strings[1].parse::<MultisigAddress>().and_then(|multisig| {
+                multisig.check_address(&expected)?;
+                Ok(multisig)
+            });
+            match imported {
+                Ok(multisig) => world.multisig = Some(multisig),
+                Err(error) => world.error = Some(error),
+            }
+        })
+        .given_regex(r#"a partially signed transaction decoded from "([^"]*)""#, |world: &mut World, strings, _step| {
+            let bytes = BASE64.decode(strings[1].as_bytes()).unwrap();
+            match PartiallySignedTransaction::from_bytes(&bytes) {
+                Ok(partially_signed) => world.partially_signed = Some(partially_signed),
+                Err(error) => world.error = Some(error),
+            }
+        })
+        .when("I start a partially signed transaction for the multisig address", |world: &mut World, _step| {
+            let transaction = world.transaction.clone().unwrap();
+            world.partially_signed = Some(PartiallySignedTransaction::new(world.multisig.as_ref().unwrap(), transaction).unwrap());
+        })
+        .when("I sign the partially signed transaction with the private key", |world: &mut World, _step| {
+            world.partially_signed.as_mut().unwrap().sign(world.account.as_ref().unwrap()).unwrap();
+        })
+        .when_regex(r#"I merge the partially signed transaction decoded from "([^"]*)""#, |world: &mut World, strings, _step| {
+            let other = PartiallySignedTransaction::from_bytes(&BASE64.decode(strings[1].as_bytes()).unwrap()).unwrap();
+            if let Err(error) = world.partially_signed.as_mut().unwrap().merge(&other) {
+                world.error = Some(error);
+            }
+        })
+        .then_regex(r#"the partially signed transaction should be signed by "([^"]*)", waiting for "([^"]*)""#, |world: &mut World, strings, _step| {
+            let partially_signed = world.partially_signed.as_ref().unwrap();
+            let join = |addresses: Vec<Address>| addresses.iter().map(Address::to_string).collect::<Vec<_>>().join(" ");
+            assert_eq!(join(partially_signed.signers()), strings[1]);
+            assert_eq!(join(partially_signed.remaining_signers()), strings[2]);
+        })
+        .then_regex(r#"the partially signed transaction should (be|not be) complete"#, |world: &mut World, strings, _step| {
+            assert_eq!(world.partially_signed.as_ref().unwrap().is_complete(), strings[1] == "be");
+        })
+        .then_regex(r#"the encoding of the partially signed transaction should equal the golden "([^"]*)""#, |world: &mut World, strings, _step| {
+            assert_eq!(BASE64.encode(&world.partially_signed.as_ref().unwrap().to_bytes().unwrap()), strings[1]);
+        })
+        .when("I finalize the partially signed transaction", |world: &mut World, _step| {
+            match world.partially_signed.clone().unwrap().finalize() {
+                Ok(signed_transaction) => world.signed_transaction = Some(signed_transaction),
+                Err(error) => world.error = Some(error),
+            }
+        })
         .given_regex(r#"encoded multisig transaction "([^"]*)""#, |world: &mut World, strings, _step| {
             let bytes = BASE64.decode(strings[1].as_bytes()).expect("Failed to decode from base64");
             let signed_transaction: SignedTransaction = rmp_serde::from_read_ref(&bytes).expect("Failed to decode");
@@ -684,7 +1294,7 @@ pub fn steps() -> Steps<World> {
             world.signed_transactions = Some(transactions);
         })
         .when("I merge the multisig transactions", |world: &mut World, _step| {
-            let signed_transaction = Account::merge_multisig_transactions(&world.signed_transactions.as_ref().unwrap()).unwrap();
+            let signed_transaction = Account::merge_multisig_transactions(world.signed_transactions.as_ref().unwrap()).unwrap();
             world.signed_transaction = Some(signed_transaction);
         })
         .when_regex(r"^I convert (\d+) microalgos to algos and back", |world: &mut World, strings, _step| {
@@ -719,9 +1329,67 @@ pub fn steps() -> Steps<World> {
             let signed_bid = rmp_serde::from_read_ref(&encoded).unwrap();
             world.signed_bid = Some(signed_bid);
         })
+        .then("the signed bid should verify", |world: &mut World, _step| {
+            world.signed_bid.unwrap().verify().unwrap();
+        })
         .then("the bid should still be the same", |world: &mut World, _step| {
             assert_eq!(world.signed_bid.unwrap(), world.old_bid.unwrap());
         })
+        .when_regex(r#"I sign the bytes "([^"]*)" with the private key"#, |world: &mut World, strings, _step| {
+            let bytes = BASE64.decode(strings[1].as_bytes()).unwrap();
+            world.bytes_signature = Some(world.account.as_ref().unwrap().sign_bytes(&bytes));
+        })
+        .then_regex(r#"the signature of the bytes should equal the golden "([^"]*)""#, |world: &mut World, strings, _step| {
+            assert_eq!(BASE64.encode(&world.bytes_signature.unwrap().0), strings[1]);
+        })
+        .then_regex(r#"the signature should (verify|not verify) for the bytes "([^"]*)""#, |world: &mut World, strings, _step| {
+            let bytes = BASE64.decode(strings[2].as_bytes()).unwrap();
+            let result = world.public_key.unwrap().verify_bytes(&bytes, &world.bytes_signature.unwrap());
+            assert_eq!(result.is_ok(), strings[1] == "verify");
+        })
+        .when("I replace the signature of the transaction with a signature of its bytes to sign", |world: &mut World, _step| {
+            let signed_transaction = world.signed_transaction.as_mut().unwrap();
+            let bytes = signed_transaction.transaction.bytes_to_sign().unwrap();
+            signed_transaction.sig = Some(world.account.as_ref().unwrap().sign_bytes(&bytes));
+        })
+        .when_regex(r#"I sign a message for "([^"]*)" with the payload (.*)$"#, |world: &mut World, strings, _step| {
+            let payload: serde_json::Value = serde_json::from_str(&strings[2]).unwrap();
+            let message = Message::new(&strings[1], payload);
+            world.signed_message = Some(world.account.as_ref().unwrap().sign_message(message).unwrap());
+        })
+        .when_regex(r#"I sign a login challenge for "([^"]*)" with the nonce (\d+)"#, |world: &mut World, strings, _step| {
+            let account = world.account.as_ref().unwrap();
+            let challenge = LoginChallenge {
+                nonce: strings[2].parse().unwrap(),
+                action: "login".to_string(),
+                session: Session {
+                    user: account.address().encode_string(),
+                    expires: 13466,
+                },
+            };
+            world.signed_challenge = Some(account.sign_message(Message::new(&strings[1], challenge)).unwrap());
+        })
+        .then_regex(r#"the signed bytes of the login challenge should be (.*)$"#, |world: &mut World, strings, _step| {
+            let bytes = world.signed_challenge.as_ref().unwrap().message.to_bytes().unwrap();
+            assert_eq!(String::from_utf8(bytes).unwrap(), strings[1]);
+        })
+        .then("the signed login challenge should verify", |world: &mut World, _step| {
+            world.signed_challenge.as_ref().unwrap().verify().unwrap();
+        })
+        .then_regex(r#"the signed bytes of the message should be (.*)$"#, |world: &mut World, strings, _step| {
+            let bytes = world.signed_message.as_ref().unwrap().message.to_bytes().unwrap();
+            assert_eq!(String::from_utf8(bytes).unwrap(), strings[1]);
+        })
+        .then("the signed message should verify", |world: &mut World, _step| {
+            world.signed_message.as_ref().unwrap().verify().unwrap();
+        })
+        .when_regex(r#"I change the domain of the signed message to "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.signed_message.as_mut().unwrap().message.domain = strings[1].clone();
+        })
+        .then_regex(r#"verifying the signed message should fail with "(.*)"$"#, |world: &mut World, strings, _step| {
+            let error = world.signed_message.as_ref().unwrap().verify().unwrap_err();
+            assert_eq!(error.to_string(), strings[1]);
+        })
         .when_regex(r#"I read a transaction "([^"]*)" from file "([^"]*)""#, |world: &mut World, strings, _step| {
             world.num = strings[2].clone();
             let path = std::env::current_dir().expect("Couldn't get current dir").parent().unwrap().join(format!("temp/raw{}.tx", strings[2]));
@@ -746,7 +1414,7 @@ pub fn steps() -> Steps<World> {
             assert_eq!(new, old);
         })
         .then("I do my part", |world: &mut World, _step| {
-            let path = std::env::current_dir().expect("Couldn't get current dir").parent().unwrap().join(format!("temp/txn.tx"));
+            let path = std::env::current_dir().expect("Couldn't get current dir").parent().unwrap().join("temp/txn.tx");
             let mut f = File::open(&path).unwrap();
             let mut bytes = Vec::new();
             let _ = f.read_to_end(&mut bytes).unwrap();
@@ -769,12 +1437,578 @@ pub fn steps() -> Steps<World> {
 
             world.account = Some(account);
         })
+        .given_regex(r#"a mock algod v2 client responding with "([^"]*)""#, |world: &mut World, strings, _step| {
+            let body = std::fs::read_to_string(fixture_path(&strings[1])).expect("Couldn't read fixture");
+            let (address, requests) = mock_server(vec![body]);
+            world.algod_v2_client = Some(AlgodClientV2::new(&address, "token"));
+            world.mock_requests = Some(requests);
+        })
+        .given_regex(r#"a mock algod v2 client responding with the fixtures "([^"]*)""#, |world: &mut World, strings, _step| {
+            let bodies = strings[1].split(", ").map(|fixture| std::fs::read_to_string(fixture_path(fixture)).expect("Couldn't read fixture")).collect();
+            let (address, requests) = mock_server(bodies);
+            world.algod_v2_client = Some(AlgodClientV2::new(&address, "token"));
+            world.mock_requests = Some(requests);
+        })
+        .when_regex(r#"I wait for the transaction "([^"]*)" to be confirmed within (\d+) rounds"#, |world: &mut World, strings, _step| {
+            let client = world.algod_v2_client.as_ref().unwrap();
+            match client.wait_for_confirmation(&strings[1], strings[2].parse().unwrap()) {
+                Ok(confirmed) => world.response = Some(serde_json::to_value(confirmed).unwrap()),
+                Err(error) => world.error = Some(error),
+            }
+        })
+        .then_regex(r#"the transaction should have been confirmed in round (\d+)"#, |world: &mut World, strings, _step| {
+            let confirmed = world.response.as_ref().expect("The call failed");
+            assert_eq!(confirmed["confirmed-round"], strings[1].parse::<u64>().unwrap());
+        })
+        .when_regex(r#"I call the algod v2 "([^"]*)" endpoint with "([^"]*)""#, |world: &mut World, strings, _step| {
+            let client = world.algod_v2_client.as_ref().unwrap();
+            let argument = &strings[2];
+            let response = match strings[1].as_str() {
+                "versions" => serde_json::to_value(client.versions().unwrap()),
+                "status" => serde_json::to_value(client.status().unwrap()),
+                "status after block" => serde_json::to_value(client.status_after_block(Round(argument.parse().unwrap())).unwrap()),
+                "block" => serde_json::to_value(client.block(Round(argument.parse().unwrap())).unwrap()),
+                "ledger supply" => serde_json::to_value(client.ledger_supply().unwrap()),
+                "account information" => serde_json::to_value(client.account_information(argument).unwrap()),
+                "account asset information" => {
+                    let (address, asset_id) = argument.split_once(' ').unwrap();
+                    serde_json::to_value(client.account_asset_information(address, asset_id.parse().unwrap()).unwrap())
+                }
+                "account application information" => {
+                    let (address, application_id) = argument.split_once(' ').unwrap();
+                    serde_json::to_value(client.account_application_information(address, application_id.parse().unwrap()).unwrap())
+                }
+                "asset information" => serde_json::to_value(client.asset_information(argument.parse().unwrap()).unwrap()),
+                "application information" => serde_json::to_value(client.application_information(argument.parse().unwrap()).unwrap()),
+                "pending transactions" => serde_json::to_value(client.pending_transactions(argument.parse().unwrap()).unwrap()),
+                "pending transactions by address" => {
+                    let (address, limit) = argument.split_once(' ').unwrap();
+                    serde_json::to_value(client.pending_transactions_by_address(address, limit.parse().unwrap()).unwrap())
+                }
+                "pending transaction information" => serde_json::to_value(client.pending_transaction_information(argument).unwrap()),
+                "raw transaction" => serde_json::to_value(client.raw_transaction(&BASE64.decode(argument.as_bytes()).unwrap()).unwrap()),
+                "transaction params" => serde_json::to_value(client.transaction_params().unwrap()),
+                "compile teal" => serde_json::to_value(client.compile_teal(argument.as_bytes()).unwrap()),
+                endpoint => panic!("Unknown endpoint {}", endpoint),
+            };
+            world.response = Some(response.unwrap());
+        })
+        .then_regex(r#"the mock server should have received "([^"]*)""#, |world: &mut World, strings, _step| {
+            let request = world.mock_requests.as_ref().unwrap().recv_timeout(Duration::from_secs(5)).expect("No request received");
+            assert_eq!(request, strings[1]);
+        })
+        .then_regex(r#"the algod v2 response should equal the fixture "([^"]*)""#, |world: &mut World, strings, _step| {
+            let fixture: serde_json::Value = serde_json::from_reader(File::open(fixture_path(&strings[1])).unwrap()).unwrap();
+            assert_eq!(world.response.as_ref().unwrap(), &fixture);
+        })
+        .given_regex(r#"a mock indexer client responding with "([^"]*)""#, |world: &mut World, strings, _step| {
+            let body = std::fs::read_to_string(fixture_path(&strings[1])).expect("Couldn't read fixture");
+            let (address, requests) = mock_server(vec![body]);
+            world.indexer_client = Some(IndexerClient::new(&address, "token"));
+            world.mock_requests = Some(requests);
+        })
+        .when_regex(r#"I call the indexer "([^"]*)" endpoint with "([^"]*)""#, |world: &mut World, strings, _step| {
+            let client = world.indexer_client.as_ref().unwrap();
+            let argument = &strings[2];
+            let response = match strings[1].as_str() {
+                "health" => serde_json::to_value(client.health().unwrap()),
+                "lookup account" => serde_json::to_value(client.lookup_account(argument).unwrap()),
+                "lookup transaction" => serde_json::to_value(client.lookup_transaction(argument).unwrap()),
+                "lookup asset" => serde_json::to_value(client.lookup_asset(argument.parse().unwrap()).unwrap()),
+                "lookup application" => serde_json::to_value(client.lookup_application(argument.parse().unwrap()).unwrap()),
+                "lookup block" => serde_json::to_value(client.lookup_block(Round(argument.parse().unwrap())).unwrap()),
+                endpoint => panic!("Unknown endpoint {}", endpoint),
+            };
+            world.response = Some(response.unwrap());
+        })
+        .when_regex(r#"I search the indexer for "([^"]*)" with filters "([^"]*)""#, |world: &mut World, strings, _step| {
+            let client = world.indexer_client.as_ref().unwrap();
+            let filters: Vec<(&str, &str)> = if strings[2] == "none" {
+                Vec::new()
+            } else {
+                strings[2].split(';').map(|filter| filter.split_once('=').unwrap()).collect()
+            };
+            let response = match strings[1].as_str() {
+                "accounts" => serde_json::to_value(client.search_accounts(&account_query(&filters)).unwrap()),
+                "transactions" => serde_json::to_value(client.search_transactions(&transaction_query(&filters)).unwrap()),
+                "account transactions" => {
+                    let (_, address) = filters.iter().find(|(name, _)| *name == "address").unwrap();
+                    let filters: Vec<_> = filters.iter().cloned().filter(|(name, _)| *name != "address").collect();
+                    serde_json::to_value(client.lookup_account_transactions(address, &transaction_query(&filters)).unwrap())
+                }
+                "assets" => serde_json::to_value(client.search_assets(&asset_query(&filters)).unwrap()),
+                "asset balances" => {
+                    let (_, asset_id) = filters.iter().find(|(name, _)| *name == "asset-id").unwrap();
+                    let filters: Vec<_> = filters.iter().cloned().filter(|(name, _)| *name != "asset-id").collect();
+                    serde_json::to_value(client.lookup_asset_balances(asset_id.parse().unwrap(), &balance_query(&filters)).unwrap())
+                }
+                "applications" => serde_json::to_value(client.search_applications(&application_query(&filters)).unwrap()),
+                endpoint => panic!("Unknown endpoint {}", endpoint),
+            };
+            world.response = Some(response.unwrap());
+        })
+        .given_regex(r#"a mock indexer client responding with the pages "([^"]*)""#, |world: &mut World, strings, _step| {
+            let bodies = strings[1].split(", ").map(|page| std::fs::read_to_string(fixture_path(page)).expect("Couldn't read fixture")).collect();
+            let (address, requests) = mock_server(bodies);
+            world.indexer_client = Some(IndexerClient::new(&address, "token"));
+            world.mock_requests = Some(requests);
+        })
+        .when_regex(r#"I iterate over the indexer transactions sent by "([^"]*)" with page size "([^"]*)" and max items "([^"]*)""#, |world: &mut World, strings, _step| {
+            let client = world.indexer_client.as_ref().unwrap();
+            let query = TransactionQuery::new()
+                .address(&Address::from_string(&strings[1]).unwrap())
+                .address_role(AddressRole::Sender);
+            let mut paginator = client.search_transactions_paginated(&query);
+            if strings[2] != "none" {
+                paginator = paginator.page_size(strings[2].parse().unwrap());
+            }
+            if strings[3] != "none" {
+                paginator = paginator.max_items(strings[3].parse().unwrap());
+            }
+            world.transaction_ids = paginator.iter().map(|transaction| transaction.unwrap().id).collect();
+        })
+        .then_regex(r#"the mock server should have received the requests "([^"]*)""#, |world: &mut World, strings, _step| {
+            let expected: Vec<_> = strings[1].split(", ").collect();
+            let requests: Vec<_> = expected.iter().map(|_| world.mock_requests.as_ref().unwrap().recv_timeout(Duration::from_secs(5)).expect("No request received")).collect();
+            assert_eq!(requests, expected);
+        })
+        .then_regex(r#"the iterated transaction ids should be "([^"]*)""#, |world: &mut World, strings, _step| {
+            assert_eq!(world.transaction_ids, strings[1].split(", ").collect::<Vec<_>>());
+        })
+        .given_regex(r#"an in-memory transport responding with status (\d+) and the fixture "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.transport_response = Some(HttpResponse {
+                status: strings[1].parse().unwrap(),
+                body: std::fs::read(fixture_path(&strings[2])).expect("Couldn't read fixture"),
+            });
+        })
+        .given_regex(r#"the custom header "([^"]*)""#, |world: &mut World, strings, _step| {
+            let (name, value) = strings[1].split_once(": ").unwrap();
+            world.transport_headers.insert(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        })
+        .when_regex(r#"I call the (algod|algod v2|indexer|kmd) "([^"]*)" endpoint through the in-memory transport"#, |world: &mut World, strings, _step| {
+            let transport = MemoryTransport {
+                requests: world.transport_requests.clone(),
+                response: world.transport_response.clone().unwrap(),
+            };
+            let response = match (strings[1].as_str(), strings[2].as_str()) {
+                ("algod", "ledger supply") => AlgodClient::new_with_transport("http://algod", "token", world.transport_headers.clone(), transport).ledger_supply().map(|response| serde_json::to_value(response).unwrap()),
+                ("algod v2", "status") => AlgodClientV2::new_with_transport("http://algod", "token", world.transport_headers.clone(), transport).status().map(|response| serde_json::to_value(response).unwrap()),
+                ("algod v2", "transaction params") => AlgodClientV2::new_with_transport("http://algod", "token", world.transport_headers.clone(), transport).transaction_params().map(|response| serde_json::to_value(response).unwrap()),
+                ("algod v2", "application information") => AlgodClientV2::new_with_transport("http://algod", "token", world.transport_headers.clone(), transport).application_information(45).map(|response| serde_json::to_value(response).unwrap()),
+                ("algod v2", "raw transaction") => AlgodClientV2::new_with_transport("http://algod", "token", world.transport_headers.clone(), transport).raw_transaction(&[1, 2, 3]).map(|response| serde_json::to_value(response).unwrap()),
+                ("indexer", "search transactions") => {
+                    let query = TransactionQuery::new().tx_type(TxType::Payment).limit(2);
+                    IndexerClient::new_with_transport("http://indexer", "token", world.transport_headers.clone(), transport).search_transactions(&query).map(|response| serde_json::to_value(response).unwrap())
+                }
+                ("kmd", "versions") => KmdClient::new_with_transport("http://kmd", "token", transport).versions().map(|response| serde_json::to_value(response.versions).unwrap()),
+                (client, endpoint) => panic!("Unknown endpoint {} {}", client, endpoint),
+            };
+            match response {
+                Ok(response) => world.response = Some(response),
+                Err(error) => world.error = Some(error),
+            }
+        })
+        .when_regex(r#"I sign transaction (\d+) of the group with the account signer"#, |world: &mut World, strings, _step| {
+            let account = world.account.as_ref().expect("No account");
+            match account.sign_group(&world.group, &[strings[1].parse().unwrap()]) {
+                Ok(signed_transactions) => world.signed_transactions = Some(signed_transactions),
+                Err(error) => world.error = Some(error),
+            }
+        })
+        .when_regex(r#"I sign transaction (\d+) of the group with the kmd signer through the in-memory transport"#, |world: &mut World, strings, _step| {
+            let transport = MemoryTransport {
+                requests: world.transport_requests.clone(),
+                response: world.transport_response.clone().unwrap(),
+            };
+            let kmd_client = KmdClient::new_with_transport("http://kmd", "token", transport);
+            let signer = KmdSigner::new(&kmd_client, "handle", "password");
+            match signer.sign_group(&world.group, &[strings[1].parse().unwrap()]) {
+                Ok(signed_transactions) => world.signed_transactions = Some(signed_transactions),
+                Err(error) => world.error = Some(error),
+            }
+        })
+        .then_regex(r#"the in-memory transport should have received "([^"]*)" with the header "([^"]*)""#, |world: &mut World, strings, _step| {
+            let requests = world.transport_requests.lock().unwrap();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(format!("{} {}", requests[0].method.as_str(), requests[0].url), strings[1]);
+            let (name, value) = strings[2].split_once(": ").unwrap();
+            assert!(requests[0].headers.iter().any(|(n, v)| n.eq_ignore_ascii_case(name) && v == value));
+        })
+        .then_regex(r#"the in-memory transport should have received the body "([^"]*)""#, |world: &mut World, strings, _step| {
+            let requests = world.transport_requests.lock().unwrap();
+            assert_eq!(requests[0].body.as_deref(), Some(strings[1].as_bytes()));
+        })
+        .then_regex(r#"the response should equal the fixture "([^"]*)""#, |world: &mut World, strings, _step| {
+            let fixture: serde_json::Value = serde_json::from_reader(File::open(fixture_path(&strings[1])).unwrap()).unwrap();
+            assert_eq!(world.response.as_ref().expect("The call failed"), &fixture);
+        })
+        .then_regex(r#"the response should be (.*)$"#, |world: &mut World, strings, _step| {
+            let expected: serde_json::Value = serde_json::from_str(&strings[1]).unwrap();
+            assert_eq!(world.response.as_ref().expect("The call failed"), &expected);
+        })
+        .then_regex(r#"the call should have failed with "(.*)"$"#, |world: &mut World, strings, _step| {
+            assert_eq!(world.error.as_ref().expect("The call succeeded").to_string(), strings[1]);
+        })
+        .when_regex(r#"^I decode the address "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.error = Address::from_string(&strings[1]).err();
+        })
+        .when_regex(r#"^I decode the mnemonic "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.error = mnemonic::to_key(&strings[1]).err();
+        })
+        .when_regex(r#"^I decode the signed transaction "([^"]*)""#, |world: &mut World, strings, _step| {
+            world.error = SignedTransaction::from_bytes(&BASE64.decode(strings[1].as_bytes()).unwrap()).err();
+        })
+        .when_regex(r#"^I create a multisig address with version (\d+) and threshold (\d+) from "([^"]*)""#, |world: &mut World, strings, _step| {
+            let addresses: Vec<Address> = strings[3].split(' ').map(|s| Address::from_string(s).unwrap()).collect();
+            world.error = MultisigAddress::new(strings[1].parse().unwrap(), strings[2].parse().unwrap(), &addresses).err();
+        })
+        .then_regex(r#"^the call should have failed with the decoding error (.*)$"#, |world: &mut World, strings, _step| {
+            match world.error.as_ref().expect("The call succeeded") {
+                algo_rust_sdk::Error::Decoding(error) => assert_eq!(format!("{:?}", error), strings[1]),
+                error => panic!("Unexpected error {:?}", error),
+            }
+        })
+        .then_regex(r#"^the call should have failed with a (decoding|validation) error "(.*)"$"#, |world: &mut World, strings, _step| {
+            let error = world.error.as_ref().expect("The call succeeded");
+            match (strings[1].as_str(), error) {
+                ("decoding", algo_rust_sdk::Error::Decoding(_)) => {}
+                ("validation", algo_rust_sdk::Error::Validation(_)) => {}
+                (_, error) => panic!("Unexpected error {:?}", error),
+            }
+            assert_eq!(error.to_string(), strings[2]);
+        })
+        .then_regex(r#"^the call should have failed with the HTTP status (\d+) and the message "(.*)"$"#, |world: &mut World, strings, _step| {
+            let error = world.error.as_ref().expect("The call succeeded");
+            assert_eq!(error.status(), Some(strings[1].parse().unwrap()));
+            match error {
+                algo_rust_sdk::Error::Http(error) => assert_eq!(error.message, strings[2]),
+                error => panic!("Unexpected error {:?}", error),
+            }
+        })
+        .then_regex(r#"^the HTTP error (should|should not) be (not found|an overspend|a dead transaction)$"#, |world: &mut World, strings, _step| {
+            let error = match world.error.as_ref().expect("The call succeeded") {
+                algo_rust_sdk::Error::Http(error) => error,
+                error => panic!("Unexpected error {:?}", error),
+            };
+            let is = match strings[2].as_str() {
+                "not found" => error.is_not_found(),
+                "an overspend" => error.is_overspend(),
+                _ => error.is_txn_dead(),
+            };
+            assert_eq!(is, strings[1] == "should");
+        })
+        .then_regex(r#"^the HTTP error data should be (.*)$"#, |world: &mut World, strings, _step| {
+            let expected: serde_json::Value = serde_json::from_str(&strings[1]).unwrap();
+            match world.error.as_ref().expect("The call succeeded") {
+                algo_rust_sdk::Error::Http(error) => assert_eq!(error.data.as_ref(), Some(&expected)),
+                error => panic!("Unexpected error {:?}", error),
+            }
+        })
+        .then_regex(r#"the indexer response should equal the fixture "([^"]*)""#, |world: &mut World, strings, _step| {
+            let fixture: serde_json::Value = serde_json::from_reader(File::open(fixture_path(&strings[1])).unwrap()).unwrap();
+            assert_eq!(world.response.as_ref().unwrap(), &fixture);
+        })
     ;
+    #[cfg(feature = "async")]
+    async_steps(&mut builder);
     builder.build()
 }
 
-#[allow(unused_imports)]
+#[cfg(feature = "async")]
+fn async_steps(builder: &mut StepsBuilder<World>) {
+    builder
+        .given_regex(r#"a mock async (algod|algod v2|indexer|kmd) client responding with "([^"]*)""#, |world: &mut World, strings, _step| {
+            let body = std::fs::read_to_string(fixture_path(&strings[2])).expect("Couldn't read fixture");
+            let (address, requests) = mock_server(vec![body]);
+            match strings[1].as_str() {
+                "algod" => world.async_algod_client = Some(AsyncAlgodClient::new(&address, "token")),
+                "algod v2" => world.async_algod_v2_client = Some(AsyncAlgodClientV2::new(&address, "token")),
+                "indexer" => world.async_indexer_client = Some(AsyncIndexerClient::new(&address, "token")),
+                _ => world.async_kmd_client = Some(AsyncKmdClient::new(&address, "token")),
+            }
+            world.mock_requests = Some(requests);
+        })
+        .given_regex(r#"a mock async indexer client responding with the pages "([^"]*)""#, |world: &mut World, strings, _step| {
+            let bodies = strings[1].split(", ").map(|page| std::fs::read_to_string(fixture_path(page)).expect("Couldn't read fixture")).collect();
+            let (address, requests) = mock_server(bodies);
+            world.async_indexer_client = Some(AsyncIndexerClient::new(&address, "token"));
+            world.mock_requests = Some(requests);
+        })
+        .given_regex(r#"a mock async algod v2 client responding with the fixtures "([^"]*)""#, |world: &mut World, strings, _step| {
+            let bodies = strings[1].split(", ").map(|fixture| std::fs::read_to_string(fixture_path(fixture)).expect("Couldn't read fixture")).collect();
+            let (address, requests) = mock_server(bodies);
+            world.async_algod_v2_client = Some(AsyncAlgodClientV2::new(&address, "token"));
+            world.mock_requests = Some(requests);
+        })
+        .when_regex(r#"I asynchronously wait for the transaction "([^"]*)" to be confirmed within (\d+) rounds"#, |world: &mut World, strings, _step| {
+            let client = world.async_algod_v2_client.as_ref().unwrap();
+            match block_on(client.wait_for_confirmation(&strings[1], strings[2].parse().unwrap())) {
+                Ok(confirmed) => world.response = Some(serde_json::to_value(confirmed).unwrap()),
+                Err(error) => world.error = Some(error),
+            }
+        })
+        .when_regex(r#"I call the async algod "([^"]*)" endpoint"#, |world: &mut World, strings, _step| {
+            let client = world.async_algod_client.as_ref().unwrap();
+            let response = match strings[1].as_str() {
+                "ledger supply" => serde_json::to_value(block_on(client.ledger_supply()).unwrap()),
+                endpoint => panic!("Unknown endpoint {}", endpoint),
+            };
+            world.response = Some(response.unwrap());
+        })
+        .when_regex(r#"I call the async algod v2 "([^"]*)" endpoint with "([^"]*)""#, |world: &mut World, strings, _step| {
+            let client = world.async_algod_v2_client.as_ref().unwrap();
+            let argument = &strings[2];
+            let response = match strings[1].as_str() {
+                "status" => serde_json::to_value(block_on(client.status()).unwrap()),
+                "block" => serde_json::to_value(block_on(client.block(Round(argument.parse().unwrap()))).unwrap()),
+                "account information" => serde_json::to_value(block_on(client.account_information(argument)).unwrap()),
+                "pending transactions" => serde_json::to_value(block_on(client.pending_transactions(argument.parse().unwrap())).unwrap()),
+                "raw transaction" => serde_json::to_value(block_on(client.raw_transaction(&BASE64.decode(argument.as_bytes()).unwrap())).unwrap()),
+                "transaction params" => serde_json::to_value(block_on(client.transaction_params()).unwrap()),
+                "compile teal" => serde_json::to_value(block_on(client.compile_teal(argument.as_bytes())).unwrap()),
+                endpoint => panic!("Unknown endpoint {}", endpoint),
+            };
+            world.response = Some(response.unwrap());
+        })
+        .when_regex(r#"I call the async indexer "([^"]*)" endpoint with "([^"]*)""#, |world: &mut World, strings, _step| {
+            let client = world.async_indexer_client.as_ref().unwrap();
+            let argument = &strings[2];
+            let response = match strings[1].as_str() {
+                "health" => serde_json::to_value(block_on(client.health()).unwrap()),
+                "lookup account" => serde_json::to_value(block_on(client.lookup_account(argument)).unwrap()),
+                "lookup block" => serde_json::to_value(block_on(client.lookup_block(Round(argument.parse().unwrap()))).unwrap()),
+                "search transactions" => {
+                    let query = TransactionQuery::new().tx_type(TxType::Payment);
+                    serde_json::to_value(block_on(client.search_transactions(&query)).unwrap())
+                }
+                endpoint => panic!("Unknown endpoint {}", endpoint),
+            };
+            world.response = Some(response.unwrap());
+        })
+        .when_regex(r#"I stream over the async indexer transactions sent by "([^"]*)" with page size "([^"]*)" and max items "([^"]*)""#, |world: &mut World, strings, _step| {
+            let client = world.async_indexer_client.as_ref().unwrap();
+            let query = TransactionQuery::new()
+                .address(&Address::from_string(&strings[1]).unwrap())
+                .address_role(AddressRole::Sender);
+            let mut paginator = client.search_transactions_paginated(&query);
+            if strings[2] != "none" {
+                paginator = paginator.page_size(strings[2].parse().unwrap());
+            }
+            if strings[3] != "none" {
+                paginator = paginator.max_items(strings[3].parse().unwrap());
+            }
+            let transactions: Vec<_> = block_on(paginator.stream().collect());
+            world.transaction_ids = transactions.into_iter().map(|transaction| transaction.unwrap().id).collect();
+        })
+        .when("I call the async kmd versions endpoint", |world: &mut World, _step| {
+            let client = world.async_kmd_client.as_ref().unwrap();
+            world.versions = block_on(client.versions()).unwrap().versions;
+        })
+        .then_regex(r#"the algod response should equal the fixture "([^"]*)""#, |world: &mut World, strings, _step| {
+            let fixture: serde_json::Value = serde_json::from_reader(File::open(fixture_path(&strings[1])).unwrap()).unwrap();
+            assert_eq!(world.response.as_ref().unwrap(), &fixture);
+        })
+        .then_regex(r#"the kmd versions should be "([^"]*)""#, |world: &mut World, strings, _step| {
+            assert_eq!(world.versions, strings[1].split(", ").collect::<Vec<_>>());
+        });
+}
+
+// Runs a future of the async clients to completion
+#[cfg(feature = "async")]
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+// Records the requests of a client and answers them with a canned response
+// Fields out of alphabetical order, to check that signed messages sort them
+#[derive(Clone, Debug, Serialize)]
+struct LoginChallenge {
+    nonce: u64,
+    action: String,
+    session: Session,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct Session {
+    user: String,
+    expires: u64,
+}
+
+struct MemoryTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+    response: HttpResponse,
+}
+
+impl HttpTransport for MemoryTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, algo_rust_sdk::Error> {
+        self.requests.lock().unwrap().push(request);
+        Ok(self.response.clone())
+    }
+}
+
+fn with_note_and_lease<T>(builder: TransactionBuilder<T>, world: &World) -> TransactionBuilder<T> {
+    let builder = builder.note(world.note.clone());
+    match world.lease {
+        Some(lease) => builder.lease(lease),
+        None => builder,
+    }
+}
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("features/resources").join(name)
+}
+
+// Serves each of `bodies` in turn as the JSON response to a request, sending back the method and path of the requests
+fn mock_server(bodies: Vec<String>) -> (String, Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = channel();
+    std::thread::spawn(move || for body in bodies {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            if header.trim_end().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut request_body = vec![0; content_length];
+        reader.read_exact(&mut request_body).unwrap();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ).unwrap();
+        let (request, _version) = request_line.trim_end().rsplit_once(' ').unwrap();
+        sender.send(request.to_string()).unwrap();
+    });
+    (address, receiver)
+}
+
+fn account_query(filters: &[(&str, &str)]) -> AccountQuery {
+    filters.iter().fold(AccountQuery::new(), |query, &(name, value)| match name {
+        "application-id" => query.application_id(value.parse().unwrap()),
+        "asset-id" => query.asset_id(value.parse().unwrap()),
+        "auth-addr" => query.auth_addr(&Address::from_string(value).unwrap()),
+        "currency-greater-than" => query.currency_greater_than(value.parse().unwrap()),
+        "currency-less-than" => query.currency_less_than(value.parse().unwrap()),
+        "include-all" => query.include_all(value.parse().unwrap()),
+        "limit" => query.limit(value.parse().unwrap()),
+        "next" => query.next(value),
+        "round" => query.round(Round(value.parse().unwrap())),
+        _ => panic!("Unknown account filter {}", name),
+    })
+}
+
+fn transaction_query(filters: &[(&str, &str)]) -> TransactionQuery {
+    filters.iter().fold(TransactionQuery::new(), |query, &(name, value)| match name {
+        "address" => query.address(&Address::from_string(value).unwrap()),
+        "address-role" => query.address_role(match value {
+            "sender" => AddressRole::Sender,
+            "receiver" => AddressRole::Receiver,
+            "freeze-target" => AddressRole::FreezeTarget,
+            _ => panic!("Unknown address role {}", value),
+        }),
+        "after-time" => query.after_time(value),
+        "application-id" => query.application_id(value.parse().unwrap()),
+        "asset-id" => query.asset_id(value.parse().unwrap()),
+        "before-time" => query.before_time(value),
+        "currency-greater-than" => query.currency_greater_than(value.parse().unwrap()),
+        "currency-less-than" => query.currency_less_than(value.parse().unwrap()),
+        "exclude-close-to" => query.exclude_close_to(value.parse().unwrap()),
+        "limit" => query.limit(value.parse().unwrap()),
+        "max-round" => query.max_round(Round(value.parse().unwrap())),
+        "min-round" => query.min_round(Round(value.parse().unwrap())),
+        "next" => query.next(value),
+        "note-prefix" => query.note_prefix(value.as_bytes()),
+        "rekey-to" => query.rekey_to(value.parse().unwrap()),
+        "round" => query.round(Round(value.parse().unwrap())),
+        "sig-type" => query.sig_type(match value {
+            "sig" => SigType::Sig,
+            "msig" => SigType::Msig,
+            "lsig" => SigType::Lsig,
+            _ => panic!("Unknown sig type {}", value),
+        }),
+        "tx-type" => query.tx_type(match value {
+            "pay" => TxType::Payment,
+            "keyreg" => TxType::KeyRegistration,
+            "acfg" => TxType::AssetConfig,
+            "axfer" => TxType::AssetTransfer,
+            "afrz" => TxType::AssetFreeze,
+            "appl" => TxType::ApplicationCall,
+            _ => panic!("Unknown tx type {}", value),
+        }),
+        "txid" => query.txid(value),
+        _ => panic!("Unknown transaction filter {}", name),
+    })
+}
+
+fn asset_query(filters: &[(&str, &str)]) -> AssetQuery {
+    filters.iter().fold(AssetQuery::new(), |query, &(name, value)| match name {
+        "asset-id" => query.asset_id(value.parse().unwrap()),
+        "creator" => query.creator(&Address::from_string(value).unwrap()),
+        "include-all" => query.include_all(value.parse().unwrap()),
+        "limit" => query.limit(value.parse().unwrap()),
+        "name" => query.name(value),
+        "next" => query.next(value),
+        "unit" => query.unit(value),
+        _ => panic!("Unknown asset filter {}", name),
+    })
+}
+
+fn balance_query(filters: &[(&str, &str)]) -> BalanceQuery {
+    filters.iter().fold(BalanceQuery::new(), |query, &(name, value)| match name {
+        "currency-greater-than" => query.currency_greater_than(value.parse().unwrap()),
+        "currency-less-than" => query.currency_less_than(value.parse().unwrap()),
+        "include-all" => query.include_all(value.parse().unwrap()),
+        "limit" => query.limit(value.parse().unwrap()),
+        "next" => query.next(value),
+        _ => panic!("Unknown balance filter {}", name),
+    })
+}
+
+fn application_query(filters: &[(&str, &str)]) -> ApplicationQuery {
+    filters.iter().fold(ApplicationQuery::new(), |query, &(name, value)| match name {
+        "application-id" => query.application_id(value.parse().unwrap()),
+        "creator" => query.creator(&Address::from_string(value).unwrap()),
+        "include-all" => query.include_all(value.parse().unwrap()),
+        "limit" => query.limit(value.parse().unwrap()),
+        "next" => query.next(value),
+        _ => panic!("Unknown application filter {}", name),
+    })
+}
+
 fn main() {
+    let features = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("features");
+    let mut paths = vec![features.join("offline")];
+    #[cfg(feature = "async")]
+    paths.push(features.join("async"));
+    // The network features need a running node, and its kmd directory in KMD_DIR
+    if std::env::var_os("KMD_DIR").is_some() {
+        paths.push(features.join("network"));
+    }
 
+    let mut cucumber = cucumber::CucumberBuilder::new(cucumber::DefaultOutput::default());
+    cucumber.features(paths).steps(steps());
+    if !cucumber.run() {
+        std::process::exit(1);
+    }
 }
 